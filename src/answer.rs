@@ -0,0 +1,72 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A part's answer. Unlike printed text, answers can be compared, hashed and
+/// serialized by kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// The puzzle has no such part, like day 25's part 2.
+    NotApplicable,
+}
+
+impl Answer {
+    pub fn is_applicable(&self) -> bool {
+        !matches!(self, Answer::NotApplicable)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+/// Reads an answer as written in an answers file: an integer if it parses as
+/// one, text otherwise.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value.parse() {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(value.to_string()),
+        })
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(
+                        i64::try_from(value).expect("answer does not fit in an i64"),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    cli::{BenchOptions, Selection},
+    runner::{self, DayResult},
+    timing::format_duration,
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Stats {
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs = sorted.iter().map(Duration::as_secs_f64).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Statistics keyed by `(year, day, phase)`.
+type Results = BTreeMap<(u16, u8, Phase), Stats>;
+
+/// Benchmarks the selected days and compares them with the stored baseline.
+/// Returns `false` if any phase regressed beyond the threshold.
+pub fn run(days: &[Selection], options: &BenchOptions) -> bool {
+    let mut results = Results::new();
+
+    for selection in days {
+        eprint!("Benchmarking day {:02}...", selection.day);
+        io::stderr().flush().ok();
+
+        for _ in 0..options.warmup {
+            runner::run_day(selection);
+        }
+
+        let runs = (0..options.runs)
+            .map_while(|_| runner::run_day(selection))
+            .collect::<Vec<_>>();
+        eprintln!(" done");
+
+        if runs.is_empty() {
+            eprintln!("Day {:02} is not implemented", selection.day);
+            continue;
+        }
+
+        collect(&mut results, selection, Phase::Parse, &runs, |r| {
+            Some(r.parse)
+        });
+        collect(&mut results, selection, Phase::Part1, &runs, |r| {
+            r.part1.time()
+        });
+        collect(&mut results, selection, Phase::Part2, &runs, |r| {
+            r.part2.time()
+        });
+    }
+
+    let baseline = match fs::read_to_string(&options.baseline) {
+        Ok(contents) => Some(parse_baseline(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", options.baseline.display(), e);
+            None
+        }
+    };
+
+    let regressions = print_report(&results, baseline.as_ref(), options.threshold);
+
+    if options.save || baseline.is_none() {
+        // Keep the baseline of days and years that were not run.
+        let mut saved = baseline.unwrap_or_default();
+        saved.extend(results);
+        match save_baseline(&options.baseline, &saved) {
+            Ok(()) => println!("\nBaseline saved to {}", options.baseline.display()),
+            Err(e) => eprintln!("Cannot write {}: {}", options.baseline.display(), e),
+        }
+    }
+
+    if regressions > 0 {
+        println!(
+            "\n{} phase(s) regressed by more than {}%",
+            regressions, options.threshold
+        );
+    }
+
+    regressions == 0
+}
+
+fn collect(
+    results: &mut Results,
+    selection: &Selection,
+    phase: Phase,
+    runs: &[DayResult],
+    time: impl Fn(&DayResult) -> Option<Duration>,
+) {
+    let samples = runs.iter().filter_map(time).collect::<Vec<_>>();
+    if !samples.is_empty() {
+        results.insert(
+            (selection.year, selection.day, phase),
+            Stats::from_samples(&samples),
+        );
+    }
+}
+
+/// Prints the statistics table and returns the number of regressions.
+fn print_report(results: &Results, baseline: Option<&Results>, threshold: f64) -> usize {
+    let mut regressions = 0;
+
+    println!(
+        "{:<5} {:<6} {:>10} {:>10} {:>10} {:>10}  Baseline",
+        "Day", "Phase", "Min", "Median", "Mean", "Std dev"
+    );
+
+    for (&(year, day, phase), stats) in results {
+        let comparison = match baseline.and_then(|b| b.get(&(year, day, phase))) {
+            Some(base) => {
+                let change = percent_change(base.median, stats.median);
+                let flag = if change > threshold {
+                    regressions += 1;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                format!(
+                    "{:>10} {:>+7.1}%{}",
+                    format_duration(base.median),
+                    change,
+                    flag
+                )
+            }
+            None => "-".to_string(),
+        };
+
+        println!(
+            "{:<5} {:<6} {:>10} {:>10} {:>10} {:>10}  {}",
+            format!("{:02}", day),
+            phase.name(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.stddev),
+            comparison
+        );
+    }
+
+    regressions
+}
+
+fn percent_change(base: Duration, current: Duration) -> f64 {
+    if base.is_zero() {
+        return 0.0;
+    }
+    (current.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Baseline lines are `year day phase runs min median mean stddev`, with times in
+/// nanoseconds. Unknown or malformed lines are ignored.
+fn parse_baseline(contents: &str) -> Results {
+    let mut results = Results::new();
+
+    for line in contents.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [year, day, phase, runs, min, median, mean, stddev] = fields[..] else {
+            continue;
+        };
+
+        let (Ok(year), Ok(day), Some(phase), Ok(runs)) = (
+            year.parse(),
+            day.parse(),
+            Phase::from_name(phase),
+            runs.parse(),
+        ) else {
+            continue;
+        };
+
+        let nanos = [min, median, mean, stddev]
+            .iter()
+            .map(|v| v.parse::<u64>().map(Duration::from_nanos))
+            .collect::<Result<Vec<_>, _>>();
+
+        if let Ok(nanos) = nanos {
+            let stats = Stats {
+                runs,
+                min: nanos[0],
+                median: nanos[1],
+                mean: nanos[2],
+                stddev: nanos[3],
+            };
+            results.insert((year, day, phase), stats);
+        }
+    }
+
+    results
+}
+
+fn save_baseline(path: &Path, results: &Results) -> io::Result<()> {
+    let mut contents = String::from("# year day phase runs min median mean stddev (ns)\n");
+    for (&(year, day, phase), stats) in results {
+        contents.push_str(&format!(
+            "{} {:02} {} {} {} {} {} {}\n",
+            year,
+            day,
+            phase.name(),
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ));
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let stats = Stats::from_samples(&millis(&[9, 1, 5]));
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+
+        // An even number of samples takes the mean of the middle two.
+        let stats = Stats::from_samples(&millis(&[8, 2, 4, 6]));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        let stats = Stats::from_samples(&millis(&[7]));
+        assert_eq!(stats.min, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn parse_baseline_skips_malformed_lines() {
+        let baseline = parse_baseline(
+            "\
+# year day phase runs min median mean stddev (ns)
+2024 01 parse 10 100 200 300 40
+2024 01 part1 10 1000 2000 3000 400
+2024 02 part3 10 1 2 3 4
+2024 03 part1 10 1 2 3
+2024 04 part1 ten 1 2 3 4
+2024 05 part2 10 1 2.5 3 4
+not a baseline line
+",
+        );
+        assert_eq!(
+            baseline.keys().copied().collect::<Vec<_>>(),
+            [(2024, 1, Phase::Parse), (2024, 1, Phase::Part1)]
+        );
+        let stats = baseline[&(2024, 1, Phase::Part1)];
+        assert_eq!(stats.runs, 10);
+        assert_eq!(stats.min, Duration::from_nanos(1000));
+        assert_eq!(stats.median, Duration::from_nanos(2000));
+        assert_eq!(stats.mean, Duration::from_nanos(3000));
+        assert_eq!(stats.stddev, Duration::from_nanos(400));
+    }
+}
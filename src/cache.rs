@@ -0,0 +1,205 @@
+//! The answer cache: answers and timings of earlier runs, reused while
+//! neither a day's input and parameters nor its code have changed.
+
+use aoc::{find_day, Answer, Params, SHARED_SOURCES};
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+pub const DEFAULT_PATH: &str = "answer_cache.txt";
+
+/// Identifies a part's answer: the part, a hash of its input text and
+/// parameters, and a hash of the code that solved it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: u64,
+    build: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub answer: Answer,
+    pub time: Duration,
+    pub parse: Duration,
+}
+
+struct Cache {
+    path: PathBuf,
+    entries: HashMap<Key, Entry>,
+    changed: bool,
+}
+
+static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+/// Turns on the cache, loading the entries saved at `path`. A missing file is
+/// an empty cache, and unreadable lines are dropped.
+pub fn enable(path: &Path) {
+    let entries = fs::read_to_string(path)
+        .map(|contents| contents.lines().filter_map(parse_entry).collect())
+        .unwrap_or_default();
+    let cache = Cache {
+        path: path.to_path_buf(),
+        entries,
+        changed: false,
+    };
+    if CACHE.set(Mutex::new(cache)).is_err() {
+        panic!("The cache can only be enabled once");
+    }
+}
+
+/// The keys of both parts of `day` of `year` for `input` and `params`, or
+/// `None` if the cache is off or the day has no solution.
+pub fn keys(year: u16, day: u8, input: &str, params: &Params) -> Option<[Key; 2]> {
+    CACHE.get()?;
+    let source = find_day(year, day)?.source;
+
+    let mut input_hash = Fnv::new();
+    input_hash.write(input.as_bytes());
+    for (key, value) in params.iter() {
+        input_hash.write(format!("{}={}", key, value).as_bytes());
+    }
+
+    let mut build_hash = Fnv::new();
+    build_hash.write(source.as_bytes());
+    for shared in SHARED_SOURCES {
+        build_hash.write(shared.as_bytes());
+    }
+
+    let key = |part| Key {
+        year,
+        day,
+        part,
+        input: input_hash.finish(),
+        build: build_hash.finish(),
+    };
+    Some([key(1), key(2)])
+}
+
+pub fn get(key: &Key) -> Option<Entry> {
+    let cache = CACHE.get()?.lock().unwrap();
+    cache.entries.get(key).cloned()
+}
+
+/// Stores a part's answer, replacing the answers of earlier builds for the
+/// same input.
+pub fn insert(key: Key, entry: Entry) {
+    let Some(cache) = CACHE.get() else {
+        return;
+    };
+    let mut cache = cache.lock().unwrap();
+    cache.entries.retain(|k, _| {
+        (k.year, k.day, k.part, k.input) != (key.year, key.day, key.part, key.input)
+    });
+    cache.entries.insert(key, entry);
+    cache.changed = true;
+}
+
+/// Writes the cache back to its file if any answer was added.
+pub fn save() -> io::Result<()> {
+    let Some(cache) = CACHE.get() else {
+        return Ok(());
+    };
+    let cache = cache.lock().unwrap();
+    if !cache.changed {
+        return Ok(());
+    }
+
+    let mut entries: Vec<_> = cache.entries.iter().collect();
+    entries.sort_by_key(|(key, _)| (key.year, key.day, key.part, key.input));
+    let contents: String = entries
+        .into_iter()
+        .map(|(key, entry)| {
+            format!(
+                "{} {} {} {:016x} {:016x} {} {} {}\n",
+                key.year,
+                key.day,
+                key.part,
+                key.input,
+                key.build,
+                entry.parse.as_nanos(),
+                entry.time.as_nanos(),
+                entry.answer
+            )
+        })
+        .collect();
+    fs::write(&cache.path, contents)
+}
+
+/// Parses a `year day part input build parse_ns time_ns answer` line.
+fn parse_entry(line: &str) -> Option<(Key, Entry)> {
+    let mut fields = line.splitn(8, ' ');
+    let mut next = || fields.next();
+
+    let key = Key {
+        year: next()?.parse().ok()?,
+        day: next()?.parse().ok()?,
+        part: next()?.parse().ok()?,
+        input: u64::from_str_radix(next()?, 16).ok()?,
+        build: u64::from_str_radix(next()?, 16).ok()?,
+    };
+    let parse = Duration::from_nanos(next()?.parse().ok()?);
+    let time = Duration::from_nanos(next()?.parse().ok()?);
+    let answer = match next()? {
+        "n/a" => Answer::NotApplicable,
+        answer => answer.parse().ok()?,
+    };
+
+    Some((
+        key,
+        Entry {
+            answer,
+            time,
+            parse,
+        },
+    ))
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` gives the same hashes in every
+/// build. Each write is followed by a separator, so that moving bytes between
+/// writes changes the hash.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Fnv {
+        Fnv(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes.iter().chain(&[0xff]) {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_saved_entries() {
+        let (key, entry) =
+            parse_entry("2024 18 2 00000000000000ff 0000000000000001 1500 2000 6,1").unwrap();
+        assert_eq!((key.year, key.day, key.part), (2024, 18, 2));
+        assert_eq!((key.input, key.build), (0xff, 1));
+        assert_eq!(entry.parse, Duration::from_nanos(1500));
+        assert_eq!(entry.time, Duration::from_nanos(2000));
+        assert_eq!(entry.answer, Answer::Text("6,1".to_string()));
+
+        let (_, entry) = parse_entry("2024 25 2 1 1 0 0 n/a").unwrap();
+        assert_eq!(entry.answer, Answer::NotApplicable);
+
+        assert!(parse_entry("2024 18 2 ff").is_none());
+    }
+}
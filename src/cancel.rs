@@ -0,0 +1,96 @@
+//! Cooperative cancellation of long-running parts. The runner installs a
+//! `Token` around each part, and loops that may run for a long time on
+//! unexpected input call `check` to stop once it is cancelled.
+
+use crate::AocError;
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Cancels the work it is installed around, either explicitly or once its
+/// deadline has passed. Clones share the same cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    /// A token that is only cancelled explicitly.
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    /// A token that is cancelled `timeout` from now, or explicitly before.
+    pub fn with_timeout(timeout: Duration) -> Token {
+        Token {
+            cancelled: Arc::default(),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Runs `f` with this token installed on the current thread, so that
+    /// `check` within `f` fails once the token is cancelled.
+    pub fn install<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Restores the previous token, even if `f` panics.
+        struct Restore(Option<Token>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Fails if the token installed on the current thread has been cancelled.
+/// Without a token, work is never cancelled.
+pub fn check() -> Result<(), AocError> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) if token.is_cancelled() => Err(AocError::new("cancelled")),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_follows_the_installed_token() {
+        let token = Token::new();
+        assert!(check().is_ok());
+        token.install(|| {
+            assert!(check().is_ok());
+            token.cancel();
+            assert!(check().is_err());
+        });
+        assert!(check().is_ok());
+
+        let expired = Token::with_timeout(Duration::ZERO);
+        assert!(expired.install(check).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    cli::Selection,
+    runner::{self, Outcome},
+};
+
+/// Reads and parses the selected days' inputs without solving them and prints
+/// each day's first problem. Returns `false` if any input does not parse.
+pub fn run(days: &[Selection]) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+
+    println!("{:<5} {:<8} Details", "Day", "Status");
+
+    for selection in days {
+        let (status, details) = match runner::check_day(selection) {
+            None => ("FAIL", "not implemented".to_string()),
+            Some(Ok(())) => ("OK", String::new()),
+            Some(Err(Outcome::Failed(message))) => ("FAIL", message),
+            Some(Err(Outcome::Panicked(message))) => ("FAIL", format!("panicked: {}", message)),
+            Some(Err(outcome)) => ("FAIL", outcome.status().to_string()),
+        };
+        if status == "OK" {
+            passed += 1;
+        } else {
+            failed += 1;
+        }
+
+        let line = format!(
+            "{:<5} {:<8} {}",
+            format!("{:02}", selection.day),
+            status,
+            details
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!("\n{} ok, {} failed", passed, failed);
+    failed == 0
+}
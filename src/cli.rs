@@ -0,0 +1,705 @@
+use crate::{
+    memory,
+    report::Format,
+    runner, serve,
+    stress::{self, StressOptions},
+    verify,
+};
+use aoc::{find_year, input::InputSource, latest_year, YEARS};
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf, time::Duration};
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+pub const USAGE: &str = "\
+Usage: aoc [COMMAND] [OPTIONS] [--all] [DAY[:PART] | FROM-TO[:PART]]...
+
+Commands:
+  run                 run the selected days and print answers and timings (default)
+  bench               run the selected days repeatedly and report statistics
+  verify              check the selected days against the expected answers
+  check               parse the selected days' inputs without solving, reporting malformed lines
+  stress              solve random generated inputs, reporting panics, errors and timeouts
+  crosscheck          solve small generated inputs and compare the answers with reference solvers
+  watch DAY           run one day, then again whenever its source or input file changes
+  serve               solve the selected days and serve their results as a web page on localhost
+  repl                an interactive prompt that keeps parsed inputs in memory (type `help` there)
+  new DAY             create src/yYYYY/dayNN.rs and an empty input file and register the day
+
+Options:
+  --year YYYY         run the days of YYYY (default the latest year)
+  --input PATH        read the selected day's input from PATH (`-` for stdin)
+  --input-dir DIR     read inputs from DIR/YYYY/dayNN.txt or DIR/dayNN.txt instead of input/
+  -                   read the selected day's input from stdin
+  -j, --jobs N        solve up to N days at once on worker threads (default 1)
+  --format FORMAT     print results as text, json or csv (default text)
+  --no-cache          solve every day instead of reusing answers from answer_cache.txt
+  --mem               report peak and allocated bytes per part (needs `--features memory`)
+  --timeout SECS      cancel a part that runs longer than SECS (default 60, 10 for stress and crosscheck, 0 for no limit)
+  --config PATH       read day parameters from PATH (default aoc.toml, if present)
+  --param DAY.KEY=VAL set a day parameter, e.g. `day14.width=11` or `2024.day14.width=11`
+
+Verify and serve options:
+  --answers PATH      expected answers, one `day part answer` per line (default answers/YYYY.txt)
+
+Serve options:
+  --port N            port to listen on (default 2024)
+
+Stress and crosscheck options:
+  --cases N           generated inputs per day (default 20)
+  --size N            size of each input, e.g. the side of a grid (default 20, 6 for crosscheck)
+  --seed S            seed of the first case, the next cases use S+1, S+2, ... (default random)
+
+Bench options:
+  --runs N            measured runs per day (default 10)
+  --warmup N          unmeasured runs before measuring (default 2)
+  --baseline PATH     baseline file to compare against (default bench_baseline.txt)
+  --save              overwrite the baseline with this run's results
+  --threshold PCT     median slowdown reported as a regression (default 10)
+
+Examples:
+  aoc            run every day
+  aoc 15         run both parts of day 15
+  aoc 15:2       run only part 2 of day 15
+  aoc 3-9        run days 3 through 9
+  aoc --all      run every day
+  aoc 6 --input example.txt
+  aoc 14:1 --input example.txt --param day14.width=11 --param day14.height=7
+  aoc --jobs 8
+  aoc bench 16 20 --runs 20
+  aoc verify --jobs 4
+  aoc check --input-dir ~/other
+  aoc stress 6 9 19 23 --cases 100 --size 50
+  aoc crosscheck 13 17 23 --cases 50
+  aoc watch 15:2
+  aoc watch 14:1 --input example.txt --param day14.width=11 --param day14.height=7
+  aoc serve --jobs 4 --port 8080
+  aoc repl --param day14.width=11 --param day14.height=7
+  aoc --year 2023 5
+  aoc new 12
+  aoc new 1 --year 2025";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub year: u16,
+    pub day: u8,
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Selection {
+    pub fn all(year: u16, day: u8) -> Selection {
+        Selection {
+            year,
+            day,
+            part1: true,
+            part2: true,
+        }
+    }
+
+    fn merge(&mut self, other: &Selection) {
+        self.part1 |= other.part1;
+        self.part2 |= other.part2;
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Run,
+    Bench(BenchOptions),
+    Verify { answers: PathBuf },
+    Check,
+    Stress(StressOptions),
+    Crosscheck(StressOptions),
+    Watch,
+    Serve { answers: PathBuf, port: u16 },
+    Repl { year: u16 },
+    New { year: u16, day: u8 },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub warmup: usize,
+    pub baseline: PathBuf,
+    pub save: bool,
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            runs: 10,
+            warmup: 2,
+            baseline: PathBuf::from("bench_baseline.txt"),
+            save: false,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub command: Command,
+    pub days: Vec<Selection>,
+    pub input: InputSource,
+    pub jobs: usize,
+    pub format: Format,
+    /// How long a part may run, `None` for no limit.
+    pub timeout: Option<Duration>,
+    /// Whether to measure each part's memory usage.
+    pub memory: bool,
+    /// Whether `run` reuses and stores answers in the answer cache.
+    pub cache: bool,
+    /// The parameters file, if given with `--config`.
+    pub config: Option<PathBuf>,
+    /// `--param` overrides, in the order given.
+    pub params: Vec<String>,
+    pub help: bool,
+}
+
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut selected: BTreeMap<u8, Selection> = BTreeMap::new();
+    let mut all = false;
+    let mut help = false;
+    let mut input = None;
+    let mut command = None;
+    let mut bench = BenchOptions::default();
+    let mut stress = StressOptions::default();
+    let mut jobs = 1;
+    let mut format = Format::Text;
+    let mut answers = None;
+    let mut port = None;
+    let mut year = None;
+    let mut config = None;
+    let mut memory = false;
+    let mut no_cache = false;
+    let mut timeout = None;
+    let mut params = Vec::new();
+
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+    while let Some(arg) = args.next() {
+        let arg = arg.as_str();
+        match arg {
+            "--all" => all = true,
+            "-h" | "--help" => help = true,
+            "-" => set_input(&mut input, InputSource::Stdin)?,
+            "--input" => {
+                let path = option_value(&mut args, arg)?;
+                let source = if path == "-" {
+                    InputSource::Stdin
+                } else {
+                    InputSource::File(PathBuf::from(path))
+                };
+                set_input(&mut input, source)?;
+            }
+            "--input-dir" => {
+                let dir = option_value(&mut args, arg)?;
+                set_input(&mut input, InputSource::Dir(PathBuf::from(dir)))?;
+            }
+            "--year" => year = Some(parse_number(&option_value(&mut args, arg)?, arg)?),
+            "-j" | "--jobs" => jobs = parse_number(&option_value(&mut args, arg)?, arg)?,
+            "--format" => format = option_value(&mut args, arg)?.parse().map_err(error)?,
+            "--timeout" => {
+                let value = option_value(&mut args, arg)?;
+                let seconds: f64 = parse_number(&value, arg)?;
+                timeout = match Duration::try_from_secs_f64(seconds) {
+                    Ok(Duration::ZERO) => Some(None),
+                    Ok(duration) => Some(Some(duration)),
+                    Err(_) => {
+                        return Err(error(format!("invalid value `{}` for `{}`", value, arg)))
+                    }
+                };
+            }
+            "--mem" => memory = true,
+            "--no-cache" => no_cache = true,
+            "--config" => config = Some(PathBuf::from(option_value(&mut args, arg)?)),
+            "--param" => params.push(option_value(&mut args, arg)?),
+            "--answers" => answers = Some(PathBuf::from(option_value(&mut args, arg)?)),
+            "--port" => port = Some(parse_number(&option_value(&mut args, arg)?, arg)?),
+            "run" | "bench" | "verify" | "check" | "stress" | "crosscheck" | "watch" | "serve"
+            | "repl" | "new"
+                if command.is_none() && selected.is_empty() =>
+            {
+                command = Some(arg.to_string());
+            }
+            "--runs" => bench.runs = parse_number(&option_value(&mut args, arg)?, arg)?,
+            "--warmup" => bench.warmup = parse_number(&option_value(&mut args, arg)?, arg)?,
+            "--baseline" => bench.baseline = PathBuf::from(option_value(&mut args, arg)?),
+            "--threshold" => bench.threshold = parse_number(&option_value(&mut args, arg)?, arg)?,
+            "--save" => bench.save = true,
+            "--cases" => stress.cases = parse_number(&option_value(&mut args, arg)?, arg)?,
+            "--size" => stress.size = Some(parse_number(&option_value(&mut args, arg)?, arg)?),
+            "--seed" => stress.seed = Some(parse_number(&option_value(&mut args, arg)?, arg)?),
+            _ if arg.starts_with("--") => {
+                return Err(error(format!("unknown option `{}`", arg)));
+            }
+            _ => {
+                for selection in parse_selection(arg)? {
+                    selected
+                        .entry(selection.day)
+                        .and_modify(|s| s.merge(&selection))
+                        .or_insert(selection);
+                }
+            }
+        }
+    }
+
+    let year = match year.or_else(latest_year) {
+        Some(year) if find_year(year).is_some() || command.as_deref() == Some("new") => year,
+        Some(year) => {
+            let known = YEARS.iter().map(|y| y.year.to_string()).collect::<Vec<_>>();
+            return Err(error(format!(
+                "no solutions for {}, expected one of {}",
+                year,
+                known.join(", ")
+            )));
+        }
+        None => return Err(error("no years are registered")),
+    };
+
+    let explicit = !(all || selected.is_empty());
+    let days: Vec<Selection> = if !explicit {
+        (FIRST_DAY..=LAST_DAY)
+            .map(|day| Selection::all(year, day))
+            .collect()
+    } else {
+        selected
+            .into_values()
+            .map(|selection| Selection { year, ..selection })
+            .collect()
+    };
+
+    let name = command.as_deref().unwrap_or("run");
+    if name != "bench" && bench != BenchOptions::default() {
+        return Err(error("bench options are only valid with `bench`"));
+    }
+    if !matches!(name, "stress" | "crosscheck") && stress != StressOptions::default() {
+        return Err(error(
+            "stress options are only valid with `stress` and `crosscheck`",
+        ));
+    }
+
+    let command = match command.as_deref() {
+        Some("bench") => {
+            if bench.runs == 0 {
+                return Err(error("`--runs` must be at least 1"));
+            }
+            if jobs > 1 {
+                return Err(error(
+                    "`bench` runs days one at a time, `--jobs` is not supported",
+                ));
+            }
+            if format != Format::Text {
+                return Err(error(
+                    "`bench` only prints text, `--format` is not supported",
+                ));
+            }
+            Command::Bench(bench)
+        }
+        Some("verify") => {
+            if format != Format::Text {
+                return Err(error(
+                    "`verify` only prints text, `--format` is not supported",
+                ));
+            }
+            Command::Verify {
+                answers: answers
+                    .take()
+                    .unwrap_or_else(|| verify::default_answers(year)),
+            }
+        }
+        Some("check") => {
+            if jobs > 1 {
+                return Err(error(
+                    "`check` parses days one at a time, `--jobs` is not supported",
+                ));
+            }
+            if format != Format::Text {
+                return Err(error(
+                    "`check` only prints text, `--format` is not supported",
+                ));
+            }
+            Command::Check
+        }
+        Some(name @ ("stress" | "crosscheck")) => {
+            if stress.cases == 0 {
+                return Err(error("`--cases` must be at least 1"));
+            }
+            if jobs > 1 {
+                return Err(error(format!(
+                    "`{}` runs cases one at a time, `--jobs` is not supported",
+                    name
+                )));
+            }
+            if format != Format::Text {
+                return Err(error(format!(
+                    "`{}` only prints text, `--format` is not supported",
+                    name
+                )));
+            }
+            if input.is_some() {
+                return Err(error(format!(
+                    "`{}` generates its inputs, `--input` is not supported",
+                    name
+                )));
+            }
+            if name == "stress" {
+                Command::Stress(stress)
+            } else {
+                Command::Crosscheck(stress)
+            }
+        }
+        Some("watch") => {
+            if all || days.len() != 1 {
+                return Err(error("`watch` expects exactly one day"));
+            }
+            if input == Some(InputSource::Stdin) {
+                return Err(error(
+                    "`watch` re-reads its input on every change, stdin is not supported",
+                ));
+            }
+            if jobs > 1 {
+                return Err(error(
+                    "`watch` runs a single day, `--jobs` is not supported",
+                ));
+            }
+            if format != Format::Text {
+                return Err(error(
+                    "`watch` only prints text, `--format` is not supported",
+                ));
+            }
+            Command::Watch
+        }
+        Some("serve") => {
+            if input == Some(InputSource::Stdin) {
+                return Err(error(
+                    "`serve` reads inputs again to re-run a day, stdin is not supported",
+                ));
+            }
+            if format != Format::Text {
+                return Err(error(
+                    "`serve` shows its results as a web page, `--format` is not supported",
+                ));
+            }
+            Command::Serve {
+                answers: answers
+                    .take()
+                    .unwrap_or_else(|| verify::default_answers(year)),
+                port: port.take().unwrap_or(serve::DEFAULT_PORT),
+            }
+        }
+        Some("repl") => {
+            if explicit {
+                return Err(error(
+                    "`repl` takes no days, load them with `load DAY` at its prompt",
+                ));
+            }
+            if matches!(input, Some(InputSource::File(_) | InputSource::Stdin)) {
+                return Err(error(
+                    "`repl` reads its commands from stdin and takes input files with `load DAY PATH`, `--input` is not supported",
+                ));
+            }
+            if jobs > 1 {
+                return Err(error(
+                    "`repl` runs one part at a time, `--jobs` is not supported",
+                ));
+            }
+            if format != Format::Text {
+                return Err(error(
+                    "`repl` only prints text, `--format` is not supported",
+                ));
+            }
+            Command::Repl { year }
+        }
+        Some("new") => {
+            let [selection] = days[..] else {
+                return Err(error("`new` expects exactly one day"));
+            };
+            if all || !(selection.part1 && selection.part2) {
+                return Err(error("`new` expects a single day, without a part"));
+            }
+            Command::New {
+                year,
+                day: selection.day,
+            }
+        }
+        _ => Command::Run,
+    };
+
+    if answers.is_some() {
+        return Err(error("`--answers` is only valid with `verify` and `serve`"));
+    }
+    if port.is_some() {
+        return Err(error("`--port` is only valid with `serve`"));
+    }
+
+    if jobs == 0 {
+        return Err(error("`--jobs` must be at least 1"));
+    }
+
+    if memory {
+        if !memory::AVAILABLE {
+            return Err(error(
+                "`--mem` needs a build with the memory feature: `cargo run --release --features memory`",
+            ));
+        }
+        if jobs > 1 {
+            return Err(error(
+                "`--mem` counts allocations for the whole process, `--jobs` is not supported",
+            ));
+        }
+        if !matches!(command, Command::Run) {
+            return Err(error("`--mem` is only valid with `run`"));
+        }
+    }
+
+    let input = input.unwrap_or(InputSource::Default);
+    if matches!(input, InputSource::File(_) | InputSource::Stdin) && days.len() != 1 {
+        return Err(error(
+            "a single input file or stdin can only be used with exactly one day",
+        ));
+    }
+
+    let timeout = timeout.unwrap_or(match command {
+        Command::Stress(_) | Command::Crosscheck(_) => Some(stress::DEFAULT_TIMEOUT),
+        _ => Some(runner::DEFAULT_TIMEOUT),
+    });
+
+    // Measuring memory needs the parts to run.
+    let cache = matches!(command, Command::Run) && !no_cache && !memory;
+
+    Ok(Options {
+        command,
+        days,
+        input,
+        jobs,
+        format,
+        timeout,
+        memory,
+        cache,
+        config,
+        params,
+        help,
+    })
+}
+
+fn option_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| error(format!("`{}` expects a value", option)))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, option: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| error(format!("invalid value `{}` for `{}`", value, option)))
+}
+
+fn set_input(input: &mut Option<InputSource>, source: InputSource) -> Result<(), String> {
+    if input.is_some() {
+        return Err(error("only one input source can be given"));
+    }
+    *input = Some(source);
+    Ok(())
+}
+
+/// Parses a day, part or range. The year is filled in once every argument has
+/// been read, as `--year` may come after the days.
+fn parse_selection(arg: &str) -> Result<Vec<Selection>, String> {
+    let (days, part) = match arg.split_once(':') {
+        Some((days, part)) => (days, Some(parse_part(part)?)),
+        None => (arg, None),
+    };
+
+    let (from, to) = match days.split_once('-') {
+        Some((from, to)) => (parse_day(from)?, parse_day(to)?),
+        None => {
+            let day = parse_day(days)?;
+            (day, day)
+        }
+    };
+
+    if from > to {
+        return Err(error(format!("invalid day range `{}`", days)));
+    }
+
+    Ok((from..=to)
+        .map(|day| Selection {
+            year: 0,
+            day,
+            part1: part.is_none_or(|p| p == 1),
+            part2: part.is_none_or(|p| p == 2),
+        })
+        .collect())
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(error(format!(
+            "invalid day `{}`, expected {}-{}",
+            value, FIRST_DAY, LAST_DAY
+        ))),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(error(format!("invalid part `{}`, expected 1 or 2", value))),
+    }
+}
+
+fn error(message: impl Display) -> String {
+    format!("error: {}\n\n{}", message, USAGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// The first line of the error for `args`, without the usage after it.
+    fn parse_error(args: &[&str]) -> String {
+        let error = parse_args(args).unwrap_err();
+        error.lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn selects_days_parts_and_ranges() {
+        let options = parse_args(["15:2", "3-4", "15:1", "--year", "2024"]).unwrap();
+        assert!(matches!(options.command, Command::Run));
+        let days: Vec<_> = options
+            .days
+            .iter()
+            .map(|s| (s.year, s.day, s.part1, s.part2))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                (2024, 3, true, true),
+                (2024, 4, true, true),
+                (2024, 15, true, true)
+            ]
+        );
+
+        let options = parse_args(["--jobs", "4", "--format", "json"]).unwrap();
+        assert_eq!(options.days.len(), usize::from(LAST_DAY));
+        assert_eq!(options.jobs, 4);
+        assert_eq!(options.format, Format::Json);
+        assert!(options.cache);
+    }
+
+    #[test]
+    fn dispatches_subcommands() {
+        let options = parse_args(["bench", "16", "--runs", "20", "--save"]).unwrap();
+        let Command::Bench(bench) = options.command else {
+            panic!("expected bench, got {:?}", options.command);
+        };
+        assert_eq!(bench.runs, 20);
+        assert!(bench.save);
+
+        let options = parse_args(["verify", "--answers", "mine.txt"]).unwrap();
+        assert!(
+            matches!(options.command, Command::Verify { answers } if answers == Path::new("mine.txt"))
+        );
+
+        let options = parse_args(["crosscheck", "17", "--cases", "5", "--seed", "3"]).unwrap();
+        let Command::Crosscheck(stress) = options.command else {
+            panic!("expected crosscheck, got {:?}", options.command);
+        };
+        assert_eq!((stress.cases, stress.seed), (5, Some(3)));
+        assert_eq!(options.timeout, Some(stress::DEFAULT_TIMEOUT));
+
+        let options = parse_args(["new", "12", "--year", "2025"]).unwrap();
+        assert!(matches!(
+            options.command,
+            Command::New {
+                year: 2025,
+                day: 12
+            }
+        ));
+
+        assert!(matches!(
+            parse_args(["check"]).unwrap().command,
+            Command::Check
+        ));
+        assert!(matches!(
+            parse_args(["watch", "15:2"]).unwrap().command,
+            Command::Watch
+        ));
+        assert!(!parse_args(["verify"]).unwrap().cache);
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid_arguments() {
+        assert_eq!(
+            parse_error(&["--frobnicate"]),
+            "error: unknown option `--frobnicate`"
+        );
+        assert_eq!(
+            parse_error(&["--jobs", "many"]),
+            "error: invalid value `many` for `--jobs`"
+        );
+        assert_eq!(
+            parse_error(&["26"]),
+            "error: invalid day `26`, expected 1-25"
+        );
+        assert_eq!(
+            parse_error(&["3:4"]),
+            "error: invalid part `4`, expected 1 or 2"
+        );
+        assert_eq!(parse_error(&["9-3"]), "error: invalid day range `9-3`");
+        assert_eq!(
+            parse_error(&["--jobs", "0"]),
+            "error: `--jobs` must be at least 1"
+        );
+        assert_eq!(
+            parse_error(&["--timeout", "-1"]),
+            "error: invalid value `-1` for `--timeout`"
+        );
+    }
+
+    #[test]
+    fn rejects_missing_values() {
+        for option in [
+            "--input", "--jobs", "--format", "--param", "--runs", "--seed",
+        ] {
+            assert_eq!(
+                parse_error(&["1", option]),
+                format!("error: `{}` expects a value", option)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_options_of_other_subcommands() {
+        for command in ["run", "verify", "check", "stress", "watch", "serve", "repl"] {
+            assert_eq!(
+                parse_error(&[command, "--runs", "3"]),
+                "error: bench options are only valid with `bench`"
+            );
+        }
+        for command in ["run", "bench", "verify", "check", "watch", "serve", "repl"] {
+            assert_eq!(
+                parse_error(&[command, "--seed", "3"]),
+                "error: stress options are only valid with `stress` and `crosscheck`"
+            );
+        }
+        for command in ["run", "bench", "check", "stress", "crosscheck", "repl"] {
+            assert_eq!(
+                parse_error(&[command, "--answers", "mine.txt"]),
+                "error: `--answers` is only valid with `verify` and `serve`"
+            );
+        }
+        assert_eq!(
+            parse_error(&["verify", "--port", "8080"]),
+            "error: `--port` is only valid with `serve`"
+        );
+    }
+}
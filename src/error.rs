@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+
+/// An error in a day's input or solution, with the position it refers to when
+/// known. Rows and columns are stored 0-based, like grid indices, and printed
+/// 1-based, like editors show them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    file: Option<String>,
+    row: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> AocError {
+        AocError {
+            file: None,
+            row: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_row(row: usize, message: impl Into<String>) -> AocError {
+        AocError {
+            row: Some(row),
+            ..AocError::new(message)
+        }
+    }
+
+    pub fn at(row: usize, column: usize, message: impl Into<String>) -> AocError {
+        AocError {
+            row: Some(row),
+            column: Some(column),
+            ..AocError::new(message)
+        }
+    }
+
+    /// Sets the file the error refers to, unless it already has one.
+    pub fn with_file(mut self, file: impl Into<String>) -> AocError {
+        self.file.get_or_insert_with(|| file.into());
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// The 1-based line number.
+    pub fn line(&self) -> Option<usize> {
+        self.row.map(|row| row + 1)
+    }
+
+    /// The 1-based column number.
+    pub fn column(&self) -> Option<usize> {
+        self.column.map(|column| column + 1)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line(), self.column()) {
+            (Some(file), Some(line), Some(column)) => write!(f, "{}:{}:{}: ", file, line, column)?,
+            (Some(file), Some(line), None) => write!(f, "{}:{}: ", file, line)?,
+            (Some(file), None, _) => write!(f, "{}: ", file)?,
+            (None, Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, None, _) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AocError {}
@@ -0,0 +1,32 @@
+//! Helpers for the per-day tests that check each solver against the examples
+//! in the puzzle text.
+
+use crate::{Answer, AocDay, AocError};
+
+/// Parses an example, failing the test with the located error if it does not
+/// parse.
+pub fn parse<D: AocDay>(input: &str) -> D::Input {
+    D::parse(input).unwrap_or_else(|e| panic!("example does not parse: {}", e))
+}
+
+pub fn assert_answer(answer: Result<Answer, AocError>, expected: impl Into<Answer>) {
+    match answer {
+        Ok(answer) => assert_eq!(answer, expected.into()),
+        Err(e) => panic!("example failed: {}", e),
+    }
+}
+
+pub fn check_part1<D: AocDay>(input: &str, expected: impl Into<Answer>) {
+    assert_answer(D::part1(&parse::<D>(input)), expected);
+}
+
+pub fn check_part2<D: AocDay>(input: &str, expected: impl Into<Answer>) {
+    assert_answer(D::part2(&parse::<D>(input)), expected);
+}
+
+pub fn check_reference<D: AocDay>(input: &str, part: u8, expected: impl Into<Answer>) {
+    match D::reference(&parse::<D>(input), part) {
+        Some(answer) => assert_answer(answer, expected),
+        None => panic!("part {} has no reference solver", part),
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+pub const DEFAULT_DIR: &str = "input";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `input/YYYY/dayNN.txt` relative to the working directory.
+    Default,
+    /// A single file, used for every selected day.
+    File(PathBuf),
+    /// A directory laid out like `input/`, or holding one year's files.
+    Dir(PathBuf),
+    /// Standard input, read once and shared by every selected day.
+    Stdin,
+}
+
+static SOURCE: OnceLock<InputSource> = OnceLock::new();
+static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+/// Sets the source used by every `read` call. Can only be set once, before any
+/// day reads its input.
+pub fn set_source(source: InputSource) {
+    SOURCE
+        .set(source)
+        .expect("Input source can only be set once");
+}
+
+pub fn source() -> &'static InputSource {
+    SOURCE.get_or_init(|| InputSource::Default)
+}
+
+/// Returns the file a day's input is read from, or `None` for stdin.
+pub fn path(year: u16, day: u8) -> Option<PathBuf> {
+    match source() {
+        InputSource::Default => Some(find_in_dir(Path::new(DEFAULT_DIR), year, day)),
+        InputSource::Dir(dir) => Some(find_in_dir(dir, year, day)),
+        InputSource::File(path) => Some(path.clone()),
+        InputSource::Stdin => None,
+    }
+}
+
+/// Reads the text of a day's input from the configured source.
+pub fn read(year: u16, day: u8) -> io::Result<String> {
+    match path(year, day) {
+        Some(path) => fs::read_to_string(&path).map_err(|e| {
+            io::Error::new(e.kind(), format!("cannot read {}: {}", path.display(), e))
+        }),
+        None => STDIN
+            .get_or_init(|| {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| format!("cannot read stdin: {}", e))
+            })
+            .clone()
+            .map_err(io::Error::other),
+    }
+}
+
+/// Prefers `YYYY/dayNN.txt`, falling back to the unpadded `dayN.txt` naming
+/// and to files directly in `dir`.
+fn find_in_dir(dir: &Path, year: u16, day: u8) -> PathBuf {
+    let year_dir = year_dir(dir, year);
+    let unpadded = format!("day{}.txt", day);
+    [
+        year_dir.join(file_name(day)),
+        year_dir.join(&unpadded),
+        dir.join(file_name(day)),
+        dir.join(&unpadded),
+    ]
+    .into_iter()
+    .find(|path| path.exists())
+    .unwrap_or_else(|| year_dir.join(file_name(day)))
+}
+
+/// The directory holding one year's inputs inside a directory laid out like
+/// `input/`.
+pub fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    dir.join(year.to_string())
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
@@ -0,0 +1,345 @@
+//! Advent of Code solutions. Each `yYYYY` module holds one year, with a
+//! `dayNN` module per day's solver; `utils` has the shared input and grid
+//! helpers, `input` the resolution of input files, `params` the per-day
+//! parameters, `cancel` the cancellation of long-running parts and `random`
+//! the random inputs for stress testing.
+
+// Grid solutions index several arrays with the same loop variable, which this
+// lint would rewrite into harder to read iterator chains.
+#![allow(clippy::needless_range_loop)]
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use params::Params;
+use random::Rng;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+pub mod answer;
+pub mod cancel;
+pub mod error;
+#[cfg(test)]
+mod examples;
+pub mod input;
+pub mod params;
+pub mod random;
+pub mod utils;
+
+/// A day's puzzle: parsing the input text, then solving both parts from the
+/// parsed input. Parsing never touches the file system, so inputs can come
+/// from files, stdin or memory alike.
+pub trait AocDay {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    /// Parses the input with the day's parameters. Days with parameters read
+    /// them here; the rest ignore them.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
+
+    /// Solves `part` the slow and obviously correct way, for days whose fast
+    /// solver relies on assumptions about the input. Returns `None` if the
+    /// day has no reference solver for the part.
+    fn reference(input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        let _ = (input, part);
+        None
+    }
+
+    /// Generates a random valid input, for stress testing. `size` scales it:
+    /// the side of a grid or the number of lines, where the puzzle allows.
+    /// Returns `None` if the day has no generator.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let _ = (rng, size);
+        None
+    }
+
+    /// Generates an input for the day's parameters. Days whose inputs must
+    /// fit their parameters, like a grid size, read them here; the rest
+    /// ignore them.
+    fn generate_with(rng: &mut Rng, size: usize, params: &Params) -> Option<String> {
+        let _ = params;
+        Self::generate(rng, size)
+    }
+}
+
+/// An `AocDay` behind a trait object, holding the parsed input between the
+/// parse and solve steps.
+pub trait Solution {
+    /// Parses the input with the day's parameters, failing on any parameter
+    /// the day does not read.
+    fn parse(&mut self, input: &str, params: &Params) -> Result<(), AocError>;
+    fn part1(&self) -> Result<Answer, AocError>;
+    fn part2(&self) -> Result<Answer, AocError>;
+    /// Solves `part` with the day's reference solver, if it has one.
+    fn reference(&self, part: u8) -> Option<Result<Answer, AocError>>;
+    /// Generates a random input for the day's parameters, if the day has a
+    /// generator.
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> Option<String>;
+}
+
+struct Solver<D: AocDay> {
+    input: Option<D::Input>,
+}
+
+impl<D: AocDay> Solver<D> {
+    fn input(&self) -> Result<&D::Input, AocError> {
+        self.input
+            .as_ref()
+            .ok_or_else(|| AocError::new("input has not been parsed"))
+    }
+}
+
+impl<D: AocDay> Solution for Solver<D> {
+    fn parse(&mut self, input: &str, params: &Params) -> Result<(), AocError> {
+        let input = D::parse_with(input, params)?;
+        params.check_all_read()?;
+        self.input = Some(input);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        D::part1(self.input()?)
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        D::part2(self.input()?)
+    }
+
+    fn reference(&self, part: u8) -> Option<Result<Answer, AocError>> {
+        match self.input() {
+            Ok(input) => D::reference(input, part),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> Option<String> {
+        D::generate_with(rng, size, params)
+    }
+}
+
+pub fn solver<D: AocDay + 'static>() -> Box<dyn Solution> {
+    Box::new(Solver::<D> { input: None })
+}
+
+/// A registered day: its puzzle number, how to build its solution and the
+/// source of its module.
+pub struct Day {
+    pub day: u8,
+    pub build: fn() -> Box<dyn Solution>,
+    pub source: &'static str,
+}
+
+/// The sources of the modules every day builds on. Together with a day's own
+/// source, they tell whether the day's code changed between builds.
+pub const SHARED_SOURCES: &[&str] = &[
+    include_str!("lib.rs"),
+    include_str!("answer.rs"),
+    include_str!("cancel.rs"),
+    include_str!("error.rs"),
+    include_str!("params.rs"),
+    include_str!("utils.rs"),
+];
+
+/// A registered year and its days.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Declares each day's module and registers its solver under an explicit day
+/// number, so the registry never depends on declaration order. Used once per
+/// year module.
+macro_rules! days {
+    ($($day:literal => $module:ident::$solver:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every registered day, in declaration order.
+        pub static DAYS: &[$crate::Day] = &[$($crate::Day {
+            day: $day,
+            build: $crate::solver::<$module::$solver>,
+            source: include_str!(concat!(stringify!($module), ".rs")),
+        }),*];
+    };
+}
+
+/// Declares each year's module and registers its days under the year.
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every registered year, in declaration order.
+        pub static YEARS: &[Year] = &[$(Year {
+            year: $year,
+            days: $module::DAYS,
+        }),*];
+    };
+}
+
+years! {
+    2024 => y2024,
+}
+
+/// The most recent registered year, which runs by default.
+pub fn latest_year() -> Option<u16> {
+    YEARS.iter().map(|y| y.year).max()
+}
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    find_year(year)?.days.iter().find(|d| d.day == day)
+}
+
+/// Returns the unparsed solution of `day` of `year`, or `None` if the day has
+/// no solution yet.
+pub fn build_day(year: u16, day: u8) -> Option<Box<dyn Solution>> {
+    find_day(year, day).map(|d| (d.build)())
+}
+
+/// A problem with the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistryIssue {
+    /// A day before the year's last registered one has no solution.
+    Missing { year: u16, day: u8 },
+    /// A day number is registered more than once; only the first is used.
+    Duplicate { year: u16, day: u8 },
+    /// A year is registered more than once; only the first is used.
+    DuplicateYear(u16),
+}
+
+impl RegistryIssue {
+    /// Whether the registry is ambiguous, rather than just incomplete.
+    pub fn is_duplicate(&self) -> bool {
+        !matches!(self, RegistryIssue::Missing { .. })
+    }
+}
+
+impl Display for RegistryIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryIssue::Missing { year, day } => {
+                write!(f, "day {} of {} is not registered", day, year)
+            }
+            RegistryIssue::Duplicate { year, day } => {
+                write!(f, "day {} of {} is registered more than once", day, year)
+            }
+            RegistryIssue::DuplicateYear(year) => {
+                write!(f, "year {} is registered more than once", year)
+            }
+        }
+    }
+}
+
+/// Lists the duplicate years, and every year's gaps and duplicate day numbers.
+pub fn check_registry(years: &[Year]) -> Vec<RegistryIssue> {
+    let mut issues = Vec::new();
+    let mut seen = BTreeSet::new();
+
+    for year in years {
+        if !seen.insert(year.year) {
+            issues.push(RegistryIssue::DuplicateYear(year.year));
+            continue;
+        }
+
+        let mut counts = BTreeMap::new();
+        for day in year.days {
+            *counts.entry(day.day).or_insert(0) += 1;
+        }
+
+        let last = counts.keys().next_back().copied().unwrap_or(0);
+        issues.extend((1..=last).filter_map(|day| match counts.get(&day) {
+            None => Some(RegistryIssue::Missing {
+                year: year.year,
+                day,
+            }),
+            Some(&count) if count > 1 => Some(RegistryIssue::Duplicate {
+                year: year.year,
+                day,
+            }),
+            _ => None,
+        }));
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A year registering `days` in the given order, all with the same solver.
+    fn year(year: u16, days: &[u8]) -> Year {
+        let days: Vec<Day> = days
+            .iter()
+            .map(|&day| Day {
+                day,
+                build: solver::<y2024::day01::AocDay1>,
+                source: "",
+            })
+            .collect();
+        Year {
+            year,
+            days: Vec::leak(days),
+        }
+    }
+
+    #[test]
+    fn generators_depend_only_on_the_seed() {
+        for year in YEARS {
+            for day in year.days {
+                let generate =
+                    |seed| (day.build)().generate(&mut Rng::new(seed), 12, &Params::new());
+                for seed in 0..3 {
+                    assert_eq!(
+                        generate(seed),
+                        generate(seed),
+                        "day {} of {}, seed {}",
+                        day.day,
+                        year.year,
+                        seed
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn registry_without_issues() {
+        assert_eq!(check_registry(&[year(2024, &[2, 1, 3])]), []);
+        assert_eq!(check_registry(YEARS), []);
+    }
+
+    #[test]
+    fn reports_gaps_and_duplicates() {
+        let issues = check_registry(&[
+            year(2023, &[1, 4, 2, 4]),
+            year(2024, &[1]),
+            year(2023, &[1]),
+        ]);
+        assert_eq!(
+            issues,
+            [
+                RegistryIssue::Missing { year: 2023, day: 3 },
+                RegistryIssue::Duplicate { year: 2023, day: 4 },
+                RegistryIssue::DuplicateYear(2023),
+            ]
+        );
+        assert!(!issues[0].is_duplicate());
+        assert!(issues[1].is_duplicate() && issues[2].is_duplicate());
+        assert_eq!(issues[0].to_string(), "day 3 of 2023 is not registered");
+        assert_eq!(
+            issues[1].to_string(),
+            "day 4 of 2023 is registered more than once"
+        );
+    }
+}
@@ -1,70 +1,185 @@
-use std::fmt::Display;
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-
-pub mod utils;
-
-trait AocDay {
-    fn part1(&self) -> Box<dyn Display>;
-    fn part2(&self) -> Box<dyn Display>;
-}
+use aoc::{check_registry, input, params, YEARS};
+use report::Format;
+use std::{env, path::Path, process, time::Instant};
+
+mod bench;
+mod cache;
+mod check;
+mod cli;
+mod memory;
+mod repl;
+mod report;
+mod runner;
+mod scaffold;
+mod serve;
+mod stress;
+mod timing;
+mod verify;
+mod watch;
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
 
 fn main() {
-    let days: Vec<Box<dyn AocDay>> = vec![
-        Box::new(day01::AocDay1::new()),
-        Box::new(day02::AocDay2::new()),
-        Box::new(day03::AocDay3::new()),
-        Box::new(day04::AocDay4::new()),
-        Box::new(day05::AocDay5::new()),
-        Box::new(day06::AocDay6::new()),
-        Box::new(day07::AocDay7::new()),
-        Box::new(day08::AocDay8::new()),
-        Box::new(day09::AocDay9::new()),
-        Box::new(day10::AocDay10::new()),
-        Box::new(day11::AocDay11::new()),
-        Box::new(day12::AocDay12::new()),
-        Box::new(day13::AocDay13::new()),
-        Box::new(day14::AocDay14::new()),
-        Box::new(day15::AocDay15::new()),
-        Box::new(day16::AocDay16::new()),
-        Box::new(day17::AocDay17::new()),
-        Box::new(day18::AocDay18::new()),
-        Box::new(day19::AocDay19::new()),
-        Box::new(day20::AocDay20::new()),
-        Box::new(day21::AocDay21::new()),
-        Box::new(day22::AocDay22::new()),
-        Box::new(day23::AocDay23::new()),
-        Box::new(day24::AocDay24::new()),
-        Box::new(day25::AocDay25::new()),
-    ];
-
-    for (i, day) in days.iter().enumerate() {
-        println!("--- Day {:02} ---", i + 1);
-        println!("Part 1: {}", day.part1());
-        println!("Part 2: {}", day.part2());
+    let options = match cli::parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    if let cli::Command::New { year, day } = options.command {
+        if let Err(message) = scaffold::create_day(year, day) {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let issues = check_registry(YEARS);
+    for issue in &issues {
+        eprintln!("warning: {}", issue);
+    }
+    if issues.iter().any(|issue| issue.is_duplicate()) {
+        eprintln!("error: duplicate registrations, fix `years!` in src/lib.rs or `days!` in src/yYYYY/mod.rs");
+        process::exit(2);
+    }
+
+    match load_config(&options) {
+        Ok(config) => params::set_config(config),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(2);
+        }
+    }
+    input::set_source(options.input.clone());
+    runner::set_timeout(options.timeout);
+    if options.memory {
+        memory::enable();
+    }
+    if options.cache {
+        cache::enable(Path::new(cache::DEFAULT_PATH));
+    }
+
+    if let cli::Command::Repl { year } = options.command {
+        repl::run(year);
+        return;
+    }
+
+    if let cli::Command::Watch = options.command {
+        if let Err(message) = watch::run(&options) {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let cli::Command::Bench(bench_options) = &options.command {
+        if !bench::run(&options.days, bench_options) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let cli::Command::Check = options.command {
+        if !check::run(&options.days) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let cli::Command::Stress(stress_options) | cli::Command::Crosscheck(stress_options) =
+        &options.command
+    {
+        let mode = match options.command {
+            cli::Command::Crosscheck(_) => stress::Mode::Crosscheck,
+            _ => stress::Mode::Stress,
+        };
+        if !stress::run(&options.days, stress_options, mode) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let cli::Command::Serve { answers, port } = &options.command {
+        let answers = verify::load_answers(answers).unwrap_or_else(|e| {
+            eprintln!(
+                "warning: cannot read {}: {}, answers are shown unverified",
+                answers.display(),
+                e
+            );
+            verify::Answers::new()
+        });
+        if let Err(message) = serve::run(&options.days, options.jobs, &answers, *port) {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let cli::Command::Verify { answers } = &options.command {
+        let answers = match verify::load_answers(answers) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Cannot read {}: {}", answers.display(), e);
+                process::exit(2);
+            }
+        };
+        if !verify::run(&options.days, options.jobs, &answers) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let start = Instant::now();
+    let mut results = Vec::new();
+    runner::run_days(&options.days, options.jobs, |selection, result| {
+        let Some(result) = result else {
+            eprintln!("Day {:02} is not implemented", selection.day);
+            return;
+        };
+        if options.format == Format::Text {
+            runner::print_answers(&result);
+        }
+        results.push(result);
+    });
+    if let Err(e) = cache::save() {
+        eprintln!("warning: cannot write {}: {}", cache::DEFAULT_PATH, e);
+    }
+
+    match options.format {
+        Format::Text => {
+            timing::print_report(&results);
+            if options.memory {
+                memory::print_report(&results);
+            }
+            if options.jobs > 1 {
+                println!("Wall time: {}", timing::format_duration(start.elapsed()));
+            }
+        }
+        Format::Json => println!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+}
+
+/// Reads the `--config` file, or `aoc.toml` if there is one, and applies the
+/// `--param` overrides.
+fn load_config(options: &cli::Options) -> Result<params::Config, String> {
+    let default = Path::new(params::DEFAULT_CONFIG);
+    let mut config = match &options.config {
+        Some(path) => params::Config::load(path).map_err(|e| e.to_string())?,
+        None if default.exists() => params::Config::load(default).map_err(|e| e.to_string())?,
+        None => params::Config::default(),
+    };
+    for spec in &options.params {
+        config.set(spec)?;
     }
+    Ok(config)
 }
@@ -0,0 +1,156 @@
+//! Memory usage per part, counted by a global allocator that is only installed
+//! when the crate is built with the `memory` feature.
+
+use crate::runner::DayResult;
+#[cfg(feature = "memory")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Whether this build counts allocations.
+pub const AVAILABLE: bool = cfg!(feature = "memory");
+
+/// Bytes currently allocated.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// The most bytes allocated at once since the last measurement started.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Bytes allocated in total, including memory that was freed again.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Passes every allocation through to the system allocator, counting it.
+#[cfg(feature = "memory")]
+pub struct Counting;
+
+#[cfg(feature = "memory")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counts a reallocation as freeing the old block and allocating the new
+    /// one, as growing a `Vec` may copy it.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "memory")]
+fn allocated(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The memory a part used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The most bytes the part had allocated at once, on top of what was
+    /// allocated before it started.
+    pub peak: usize,
+    /// Bytes allocated in total, including memory that was freed again.
+    pub allocated: usize,
+    pub allocations: usize,
+}
+
+/// Turns on measuring parts. The counters are process-wide, so parts must
+/// run one at a time.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Runs `f`, returning the memory it used if measuring is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let value = f();
+
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (value, Some(usage))
+}
+
+pub fn print_report(results: &[DayResult]) {
+    println!();
+    println!(
+        "{:<5} {:<5} {:>10} {:>10} {:>12}",
+        "Day", "Part", "Peak", "Allocated", "Allocations"
+    );
+
+    for result in results {
+        for (part, part_result) in result.parts() {
+            let Some(usage) = part_result.memory else {
+                continue;
+            };
+            println!(
+                "{:<5} {:<5} {:>10} {:>10} {:>12}",
+                format!("{:02}", result.day),
+                part,
+                format_bytes(usage.peak),
+                format_bytes(usage.allocated),
+                usage.allocations,
+            );
+        }
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes_in_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+    }
+}
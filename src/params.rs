@@ -0,0 +1,312 @@
+//! Per-day parameters: puzzle constants such as grid sizes that differ between
+//! the examples and the real input. They are read from an `aoc.toml` style
+//! config file and `--param` overrides.
+
+use crate::AocError;
+use std::{
+    any,
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs,
+    path::Path,
+    str::FromStr,
+    sync::OnceLock,
+};
+
+pub const DEFAULT_CONFIG: &str = "aoc.toml";
+
+/// A parameter's value and where it was set, for error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Value {
+    text: String,
+    file: String,
+    row: Option<usize>,
+}
+
+impl Value {
+    fn error(&self, message: String) -> AocError {
+        let error = match self.row {
+            Some(row) => AocError::at_row(row, message),
+            None => AocError::new(message),
+        };
+        error.with_file(self.file.clone())
+    }
+}
+
+/// One day's parameters. Days read them with `get` while parsing; any
+/// parameter a day never reads is reported as unknown.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, Value>,
+    read: RefCell<BTreeSet<String>>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    /// Returns the parameter `key`, or `default` if it is not set.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, AocError> {
+        self.read.borrow_mut().insert(key.to_string());
+        let Some(value) = self.values.get(key) else {
+            return Ok(default);
+        };
+
+        value.text.parse().map_err(|_| {
+            value.error(format!(
+                "parameter `{}` expects {}, found `{}`",
+                key,
+                any::type_name::<T>(),
+                value.text
+            ))
+        })
+    }
+
+    /// Like `get`, but rejects values below `min`.
+    pub fn get_at_least<T>(&self, key: &str, default: T, min: T) -> Result<T, AocError>
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        let value = self.get(key, default)?;
+        match self.values.get(key) {
+            Some(set) if value < min => Err(set.error(format!(
+                "parameter `{}` must be at least {}, found `{}`",
+                key, min, set.text
+            ))),
+            _ => Ok(value),
+        }
+    }
+
+    /// The parameters as `(key, value)` pairs, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.text.as_str()))
+    }
+
+    /// Fails with the first parameter that was set but never read.
+    pub fn check_all_read(&self) -> Result<(), AocError> {
+        let read = self.read.borrow();
+        match self.values.iter().find(|(key, _)| !read.contains(*key)) {
+            Some((key, value)) => Err(value.error(format!("unknown parameter `{}`", key))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parameters for `(year, day)`; a `None` year applies to every year.
+type DayKey = (Option<u16>, u8);
+
+/// The parameters of every day, from a config file and `--param` overrides.
+///
+/// The config file has one section per day, optionally qualified with a year,
+/// holding `key = value` lines:
+///
+/// ```toml
+/// [day14]
+/// width = 11
+/// height = 7
+///
+/// [2024.day20]
+/// min_saved = 64
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    file: BTreeMap<DayKey, BTreeMap<String, Value>>,
+    overrides: BTreeMap<DayKey, BTreeMap<String, Value>>,
+}
+
+impl Config {
+    /// Reads the config file at `path`.
+    pub fn load(path: &Path) -> Result<Config, AocError> {
+        let file = path.display().to_string();
+        let text = fs::read_to_string(path)
+            .map_err(|e| AocError::new(format!("cannot read config: {}", e)).with_file(&file))?;
+        Config::parse(&text, &file).map_err(|e| e.with_file(&file))
+    }
+
+    /// Parses the text of a config file named `file`.
+    pub fn parse(text: &str, file: &str) -> Result<Config, AocError> {
+        let mut config = Config::default();
+        let mut section = None;
+
+        for (row, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| AocError::at_row(row, "expected `]` after the section name"))?;
+                let key = parse_day_key(name.trim()).ok_or_else(|| {
+                    AocError::at_row(
+                        row,
+                        format!(
+                            "expected a section like `[day14]` or `[2024.day14]`, found `[{}]`",
+                            name
+                        ),
+                    )
+                })?;
+                section = Some(key);
+                continue;
+            }
+
+            let Some(day) = section else {
+                return Err(AocError::at_row(
+                    row,
+                    "expected a `[dayN]` section before parameters",
+                ));
+            };
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| AocError::at_row(row, "expected `key = value`"))?;
+            let key = key.trim();
+            if !is_key(key) {
+                return Err(AocError::at_row(
+                    row,
+                    format!("invalid parameter name `{}`", key),
+                ));
+            }
+
+            let value = Value {
+                text: unquote(value.trim()).to_string(),
+                file: file.to_string(),
+                row: Some(row),
+            };
+            config
+                .file
+                .entry(day)
+                .or_default()
+                .insert(key.to_string(), value);
+        }
+
+        Ok(config)
+    }
+
+    /// Applies a `--param` override such as `day14.width=11` or
+    /// `2024.day14.width=11`.
+    pub fn set(&mut self, spec: &str) -> Result<(), String> {
+        let invalid = || {
+            format!(
+                "invalid parameter `{}`, expected `[YEAR.]dayN.key=value`",
+                spec
+            )
+        };
+        let (name, value) = spec.split_once('=').ok_or_else(invalid)?;
+        let (day, key) = name.rsplit_once('.').ok_or_else(invalid)?;
+        let day = parse_day_key(day).ok_or_else(invalid)?;
+        if !is_key(key) {
+            return Err(invalid());
+        }
+
+        let value = Value {
+            text: unquote(value.trim()).to_string(),
+            file: format!("--param {}", spec),
+            row: None,
+        };
+        self.overrides
+            .entry(day)
+            .or_default()
+            .insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// The parameters of `day` of `year`. Year sections override plain day
+    /// sections, and `--param` overrides both.
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        let mut params = Params::new();
+        for layer in [&self.file, &self.overrides] {
+            for key in [(None, day), (Some(year), day)] {
+                if let Some(values) = layer.get(&key) {
+                    params.values.extend(values.clone());
+                }
+            }
+        }
+        params
+    }
+}
+
+/// Parses `day14`, `day04` or `2024.day14`.
+fn parse_day_key(name: &str) -> Option<DayKey> {
+    let (year, day) = match name.split_once('.') {
+        Some((year, day)) => (Some(year.parse().ok()?), day),
+        None => (None, name),
+    };
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    Some((year, day))
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Sets the config every day's parameters are taken from. Can only be set
+/// once, before any day is parsed.
+pub fn set_config(config: Config) {
+    CONFIG.set(config).expect("Config can only be set once");
+}
+
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+# example sizes
+[day14]
+width = 11
+height = 7
+
+[2024.day14]
+height = 9
+";
+
+    #[test]
+    fn year_sections_and_overrides_take_precedence() {
+        let mut config = Config::parse(CONFIG, "aoc.toml").unwrap();
+        let params = config.params(2024, 14);
+        assert_eq!(params.get("width", 101), Ok(11));
+        assert_eq!(params.get("height", 103), Ok(9));
+        assert_eq!(config.params(2023, 14).get("height", 103), Ok(7));
+
+        config.set("day14.height=5").unwrap();
+        assert_eq!(config.params(2024, 14).get("height", 103), Ok(5));
+    }
+
+    #[test]
+    fn reports_unread_and_invalid_parameters() {
+        let config = Config::parse(CONFIG, "aoc.toml").unwrap();
+        let params = config.params(2023, 14);
+        assert_eq!(params.get("missing", 1), Ok(1));
+        assert_eq!(params.get("width", 0), Ok(11));
+        let error = params.check_all_read().unwrap_err();
+        assert_eq!(error.to_string(), "aoc.toml:4: unknown parameter `height`");
+
+        let mut config = Config::default();
+        config.set("day14.width=wide").unwrap();
+        assert!(config.params(2024, 14).get("width", 101).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_config() {
+        assert!(Config::parse("width = 11", "aoc.toml").is_err());
+        assert!(Config::parse("[dayX]", "aoc.toml").is_err());
+        assert!(Config::default().set("width=11").is_err());
+    }
+}
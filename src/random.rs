@@ -0,0 +1,153 @@
+//! Random puzzle inputs for stress testing: a small seeded generator, so that
+//! every input can be generated again from its seed, and helpers shared by
+//! the days' generators.
+
+use std::ops::Range;
+
+/// A SplitMix64 generator. Not suitable for anything but test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. Panics if `n` is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `range`. Panics if the range is empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "Cannot pick from an empty range");
+        let span = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// A random element of `items`. Panics if it is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A maze of `#` walls and `.` paths, with exactly one path between any two
+/// open cells. Open cells are at odd coordinates, so `rows` and `cols` should
+/// be odd; the border is wall.
+pub fn maze(rng: &mut Rng, rows: usize, cols: usize) -> Vec<Vec<char>> {
+    let mut grid = vec![vec!['#'; cols]; rows];
+    let mut stack = vec![(1, 1)];
+    grid[1][1] = '.';
+
+    while let Some(&(r, c)) = stack.last() {
+        let mut next = Vec::new();
+        for (dr, dc) in [(0, 2), (2, 0), (0, -2), (-2, 0)] {
+            let (nr, nc) = (r as isize + dr, c as isize + dc);
+            if nr > 0
+                && nc > 0
+                && (nr as usize) < rows - 1
+                && (nc as usize) < cols - 1
+                && grid[nr as usize][nc as usize] == '#'
+            {
+                next.push((nr as usize, nc as usize));
+            }
+        }
+
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (nr, nc) = *rng.pick(&next);
+        grid[(r + nr) / 2][(c + nc) / 2] = '.';
+        grid[nr][nc] = '.';
+        stack.push((nr, nc));
+    }
+
+    grid
+}
+
+/// Joins a grid's rows into lines.
+pub fn grid_text(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Params;
+
+    #[test]
+    fn same_seed_gives_the_same_numbers() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-5..5).contains(&rng.range(-5..5)));
+        }
+    }
+
+    #[test]
+    fn every_day_generates_inputs_it_parses() {
+        for year in crate::YEARS {
+            for day in year.days {
+                let mut solution = (day.build)();
+                for seed in 0..3 {
+                    let input = solution
+                        .generate(&mut Rng::new(seed), 10, &Params::new())
+                        .unwrap_or_else(|| panic!("day {} has no generator", day.day));
+                    if let Err(e) = solution.parse(&input, &Params::new()) {
+                        panic!("day {} seed {}: {}", day.day, seed, e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn maze_connects_every_open_cell() {
+        let grid = maze(&mut Rng::new(3), 9, 11);
+        let open = grid.iter().flatten().filter(|&&c| c == '.').count();
+
+        let mut seen = vec![(1, 1)];
+        let mut i = 0;
+        while let Some(&(r, c)) = seen.get(i) {
+            i += 1;
+            for (nr, nc) in [(r + 1, c), (r - 1, c), (r, c + 1), (r, c - 1)] {
+                if grid[nr][nc] == '.' && !seen.contains(&(nr, nc)) {
+                    seen.push((nr, nc));
+                }
+            }
+        }
+        assert_eq!(seen.len(), open);
+        assert_eq!(open, 4 * 5 + 4 * 5 - 1);
+    }
+}
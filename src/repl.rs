@@ -0,0 +1,357 @@
+//! An interactive prompt for experimenting with days: `load` reads and parses
+//! an input once, and parts then run on the parsed input as often as needed.
+//! Changing a parameter re-parses the loaded inputs it affects from the text
+//! kept in memory.
+
+use crate::{
+    runner::{self, DayResult, Outcome, PartResult},
+    timing,
+};
+use aoc::{build_day, input, params, params::Config, Solution};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+const HELP: &str = "\
+Commands:
+  load DAY [PATH]     read and parse a day's input (default its input file) and select the day
+  day DAY             select a day loaded before
+  part N              solve part N of the selected day
+  time                solve both parts of the selected day and show the timings
+  param KEY VALUE     set a parameter, e.g. `param day14.width 11`, and re-parse the days it affects
+  show                show the loaded days and the selected day's parameters and last answers
+  help                show this list
+  quit                leave the prompt (also Ctrl-D)";
+
+/// A day whose input is kept in memory.
+struct Loaded {
+    /// Where the input was read from, which parse errors point at.
+    name: String,
+    text: String,
+    solution: Box<dyn Solution>,
+    parsed: Result<(), Outcome>,
+    parse: Duration,
+    /// The last result of each part since the input was parsed.
+    last: [Option<PartResult>; 2],
+}
+
+impl Loaded {
+    /// Parses the kept text again, e.g. with changed parameters.
+    fn parse(&mut self, config: &Config, year: u16, day: u8) {
+        let params = config.params(year, day);
+        let (parsed, parse) =
+            runner::parse_text(self.solution.as_mut(), &self.text, &self.name, &params);
+        self.parsed = parsed;
+        self.parse = parse;
+        self.last = [None, None];
+    }
+
+    fn print_parse(&self, day: u8) {
+        match &self.parsed {
+            Ok(()) => println!(
+                "Day {:02}: parsed {} lines of {} in {}",
+                day,
+                self.text.lines().count(),
+                self.name,
+                timing::format_duration(self.parse)
+            ),
+            Err(outcome) => println!("Day {:02}: {}", day, failure(outcome)),
+        }
+    }
+}
+
+struct Repl {
+    year: u16,
+    /// The parameters, starting from `aoc.toml` and `--param` and changed with
+    /// `param`.
+    config: Config,
+    days: BTreeMap<u8, Loaded>,
+    selected: Option<u8>,
+}
+
+/// Reads commands from stdin until `quit` or the end of input. The prompt is
+/// only shown on a terminal, so commands can also be piped in.
+pub fn run(year: u16) {
+    let mut repl = Repl::new(year);
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!("Advent of Code {}, type `help` for the commands", year);
+    }
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("aoc> ");
+            let _ = io::stdout().flush();
+        }
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match repl.execute(&line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(message) => println!("error: {}", message),
+        }
+    }
+}
+
+impl Repl {
+    fn new(year: u16) -> Repl {
+        Repl {
+            year,
+            config: params::config().clone(),
+            days: BTreeMap::new(),
+            selected: None,
+        }
+    }
+
+    /// Runs one command line, returning `false` once the prompt should close.
+    fn execute(&mut self, line: &str) -> Result<bool, String> {
+        let line = line.trim();
+        let (command, rest) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, rest)| (command, rest.trim()));
+
+        match (command, rest) {
+            ("", _) => {}
+            ("load", rest) => {
+                let (day, path) = match rest.split_once(char::is_whitespace) {
+                    Some((day, path)) => (day, Some(PathBuf::from(path.trim()))),
+                    None => (rest, None),
+                };
+                self.load(parse_day(day)?, path)?;
+            }
+            ("day", day) => {
+                let day = parse_day(day)?;
+                if !self.days.contains_key(&day) {
+                    return Err(format!("day {} is not loaded, use `load {}`", day, day));
+                }
+                self.selected = Some(day);
+            }
+            ("part", "1") => self.part(1)?,
+            ("part", "2") => self.part(2)?,
+            ("part", part) => return Err(format!("invalid part `{}`, expected 1 or 2", part)),
+            ("time", "") => self.time()?,
+            ("param", spec) => {
+                // `param day14.width 11` or `param day14.width=11`.
+                let spec = match spec.split_once(char::is_whitespace) {
+                    Some((key, value)) => format!("{}={}", key, value.trim()),
+                    None => spec.to_string(),
+                };
+                self.set_param(&spec)?;
+            }
+            ("show", "") => self.show()?,
+            ("help", "") => println!("{}", HELP),
+            ("quit" | "exit", "") => return Ok(false),
+            _ => {
+                return Err(format!(
+                    "unknown command `{}`, type `help` for the commands",
+                    line
+                ))
+            }
+        }
+        Ok(true)
+    }
+
+    /// Reads and parses a day's input, keeping it even if it does not parse so
+    /// that a parameter change can fix it, and selects the day.
+    fn load(&mut self, day: u8, path: Option<PathBuf>) -> Result<(), String> {
+        let solution = build_day(self.year, day)
+            .ok_or_else(|| format!("day {} of {} is not implemented", day, self.year))?;
+        let path = path
+            .or_else(|| input::path(self.year, day))
+            .ok_or("no input file, give a path")?;
+        let name = path.display().to_string();
+        let text = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", name, e))?;
+
+        let mut loaded = Loaded {
+            name,
+            text,
+            solution,
+            parsed: Ok(()),
+            parse: Duration::ZERO,
+            last: [None, None],
+        };
+        loaded.parse(&self.config, self.year, day);
+        loaded.print_parse(day);
+        self.days.insert(day, loaded);
+        self.selected = Some(day);
+        Ok(())
+    }
+
+    fn selected(&mut self) -> Result<(u8, &mut Loaded), String> {
+        let day = self
+            .selected
+            .ok_or("no day is loaded, use `load DAY [PATH]`")?;
+        let loaded = self.days.get_mut(&day).expect("the selected day is loaded");
+        Ok((day, loaded))
+    }
+
+    /// The selected day, if its input parsed.
+    fn parsed(&mut self) -> Result<(u8, &mut Loaded), String> {
+        let (day, loaded) = self.selected()?;
+        if let Err(outcome) = &loaded.parsed {
+            return Err(format!("day {} did not parse: {}", day, failure(outcome)));
+        }
+        Ok((day, loaded))
+    }
+
+    fn part(&mut self, part: u8) -> Result<(), String> {
+        let (_, loaded) = self.parsed()?;
+        let result = runner::run_parsed(loaded.solution.as_ref(), part);
+        print_part(part, &result);
+        loaded.last[usize::from(part) - 1] = Some(result);
+        Ok(())
+    }
+
+    fn time(&mut self) -> Result<(), String> {
+        let year = self.year;
+        let (day, loaded) = self.parsed()?;
+        let result = DayResult {
+            year,
+            day,
+            parse: loaded.parse,
+            part1: runner::run_parsed(loaded.solution.as_ref(), 1),
+            part2: runner::run_parsed(loaded.solution.as_ref(), 2),
+        };
+        runner::print_answers(&result);
+        timing::print_report(std::slice::from_ref(&result));
+        loaded.last = [Some(result.part1), Some(result.part2)];
+        Ok(())
+    }
+
+    /// Sets a parameter and re-parses every loaded day whose parameters it
+    /// changes.
+    fn set_param(&mut self, spec: &str) -> Result<(), String> {
+        let before: Vec<_> = self
+            .days
+            .keys()
+            .map(|&day| param_values(&self.config, self.year, day))
+            .collect();
+        self.config.set(spec)?;
+
+        let mut reparsed = false;
+        for ((&day, loaded), before) in self.days.iter_mut().zip(before) {
+            if param_values(&self.config, self.year, day) != before {
+                loaded.parse(&self.config, self.year, day);
+                loaded.print_parse(day);
+                reparsed = true;
+            }
+        }
+        if !reparsed {
+            println!("Set {}, no loaded day uses it", spec);
+        }
+        Ok(())
+    }
+
+    fn show(&mut self) -> Result<(), String> {
+        let loaded: Vec<String> = self
+            .days
+            .iter()
+            .map(|(day, loaded)| format!("{} ({})", day, loaded.name))
+            .collect();
+        println!("Loaded: {}", loaded.join(", "));
+
+        let year = self.year;
+        let config = self.config.clone();
+        let (day, loaded) = self.selected()?;
+        loaded.print_parse(day);
+        let params: Vec<String> = param_values(&config, year, day)
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        if !params.is_empty() {
+            println!("Parameters: {}", params.join(", "));
+        }
+        for (i, last) in loaded.last.iter().enumerate() {
+            let part = i as u8 + 1;
+            match last {
+                Some(result) => print_part(part, result),
+                None => println!("Part {}: not run", part),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn print_part(part: u8, result: &PartResult) {
+    if let Some(line) = runner::answer_line(part, result) {
+        println!("{}  ({})", line, timing::format_duration(result.time));
+    }
+}
+
+/// Why an input did not parse.
+fn failure(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Failed(message) => format!("error: {}", message),
+        Outcome::Panicked(message) => format!("panicked: {}", message),
+        outcome => outcome.status().to_string(),
+    }
+}
+
+/// A day's parameters, sorted by key.
+fn param_values(config: &Config, year: u16, day: u8) -> Vec<(String, String)> {
+    let mut values: Vec<_> = config
+        .params(year, day)
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    values.sort();
+    values
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected 1 to 25", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{y2024::day14::EXAMPLE, Answer};
+    use std::{env, process};
+
+    fn answer(repl: &Repl, day: u8, part: usize) -> Option<Answer> {
+        match &repl.days[&day].last[part - 1].as_ref()?.outcome {
+            Outcome::Solved(answer) => Some(answer.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn param_reparses_the_loaded_input() {
+        let path = env::temp_dir().join(format!("aoc-repl-{}.txt", process::id()));
+        fs::write(&path, EXAMPLE).unwrap();
+        let mut repl = Repl::new(2024);
+
+        let load = format!("load 14 {}", path.display());
+        assert_eq!(repl.execute(&load), Ok(true));
+        assert_eq!(repl.execute("part 1"), Ok(true));
+        assert!(answer(&repl, 14, 1).is_some());
+        fs::remove_file(&path).unwrap();
+
+        // The input stays in memory, so the file is not needed to re-parse.
+        assert_eq!(repl.execute("param day14.width 11"), Ok(true));
+        assert_eq!(repl.execute("param day14.height=7"), Ok(true));
+        assert_eq!(answer(&repl, 14, 1), None);
+        assert_eq!(repl.execute("part 1"), Ok(true));
+        assert_eq!(answer(&repl, 14, 1), Some(Answer::Integer(12)));
+    }
+
+    #[test]
+    fn rejects_commands_without_a_loaded_day() {
+        let mut repl = Repl::new(2024);
+        assert!(repl.execute("part 1").is_err());
+        assert!(repl.execute("day 3").is_err());
+        assert!(repl.execute("load 26").is_err());
+        assert!(repl.execute("frobnicate").is_err());
+        assert_eq!(repl.execute(""), Ok(true));
+        assert_eq!(repl.execute("quit"), Ok(false));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::runner::{DayResult, Outcome, PartResult};
+use aoc::Answer;
+use std::{fmt::Write, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format `{}`, expected json, csv or text",
+                value
+            )),
+        }
+    }
+}
+
+/// One line of machine-readable output: a single part of a single day.
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    parse_ns: u128,
+    result: &'a PartResult,
+}
+
+impl Record<'_> {
+    fn answer(&self) -> Option<&Answer> {
+        match &self.result.outcome {
+            Outcome::Solved(answer) if answer.is_applicable() => Some(answer),
+            _ => None,
+        }
+    }
+
+    fn error(&self) -> Option<&str> {
+        match &self.result.outcome {
+            Outcome::Failed(message) | Outcome::Panicked(message) => Some(message),
+            Outcome::TimedOut => Some("timed out"),
+            _ => None,
+        }
+    }
+
+    fn time_ns(&self) -> Option<u128> {
+        self.result.time().map(|t| t.as_nanos())
+    }
+
+    /// Peak bytes, allocated bytes and allocation count, if measured.
+    fn memory(&self) -> [Option<usize>; 3] {
+        match self.result.memory {
+            Some(usage) => [
+                Some(usage.peak),
+                Some(usage.allocated),
+                Some(usage.allocations),
+            ],
+            None => [None; 3],
+        }
+    }
+}
+
+fn records(results: &[DayResult]) -> impl Iterator<Item = Record<'_>> {
+    results.iter().flat_map(|day| {
+        day.parts().into_iter().map(move |(part, result)| Record {
+            year: day.year,
+            day: day.day,
+            part,
+            parse_ns: day.parse.as_nanos(),
+            result,
+        })
+    })
+}
+
+pub fn to_json(results: &[DayResult]) -> String {
+    let mut out = String::from("[");
+    for (i, record) in records(results).enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let [peak, allocated, allocations] = record.memory();
+        write!(
+            out,
+            "\n  {{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"error\": {}, \"parse_ns\": {}, \"time_ns\": {}, \"cached\": {}, \"peak_bytes\": {}, \"allocated_bytes\": {}, \"allocations\": {}}}",
+            record.year,
+            record.day,
+            record.part,
+            record.result.outcome.status(),
+            json_answer(record.answer()),
+            json_string(record.error()),
+            record.parse_ns,
+            record.time_ns().map_or("null".to_string(), |t| t.to_string()),
+            record.result.cached,
+            json_number(peak),
+            json_number(allocated),
+            json_number(allocations),
+        )
+        .unwrap();
+    }
+    out.push_str("\n]");
+    out
+}
+
+pub fn to_csv(results: &[DayResult]) -> String {
+    let mut out = String::from(
+        "year,day,part,status,answer,error,parse_ns,time_ns,cached,peak_bytes,allocated_bytes,allocations\n",
+    );
+    for record in records(results) {
+        let [peak, allocated, allocations] = record.memory();
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            record.result.outcome.status(),
+            csv_field(&record.answer().map_or(String::new(), |a| a.to_string())),
+            csv_field(record.error().unwrap_or("")),
+            record.parse_ns,
+            record.time_ns().map_or(String::new(), |t| t.to_string()),
+            record.result.cached,
+            peak.map_or(String::new(), |v| v.to_string()),
+            allocated.map_or(String::new(), |v| v.to_string()),
+            allocations.map_or(String::new(), |v| v.to_string()),
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Integers are written as JSON numbers, text as strings and a missing or
+/// not-applicable answer as `null`.
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Integer(value)) => value.to_string(),
+        Some(Answer::Text(text)) => json_string(Some(text)),
+        Some(Answer::NotApplicable) | None => json_string(None),
+    }
+}
+
+fn json_number(value: Option<usize>) -> String {
+    value.map_or("null".to_string(), |v| v.to_string())
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes a field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn day(part1: Outcome, part2: Outcome) -> DayResult {
+        let part = |outcome| PartResult {
+            outcome,
+            time: Duration::from_nanos(250),
+            memory: None,
+            cached: false,
+        };
+        DayResult {
+            year: 2024,
+            day: 7,
+            parse: Duration::from_nanos(100),
+            part1: part(part1),
+            part2: part(part2),
+        }
+    }
+
+    #[test]
+    fn json_escapes_strings() {
+        assert_eq!(json_string(None), "null");
+        assert_eq!(json_string(Some("plain")), "\"plain\"");
+        assert_eq!(
+            json_string(Some("say \"hi\", \\ then\n\r\t\u{1}")),
+            "\"say \\\"hi\\\", \\\\ then\\n\\r\\t\\u0001\""
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields_that_need_it() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn records_with_text_answers_and_errors() {
+        let results = [day(
+            Outcome::Solved(Answer::Text("\"a\",b".to_string())),
+            Outcome::Failed("line 1, column 2:\nexpected `\"`".to_string()),
+        )];
+
+        let json = to_json(&results);
+        assert!(json.contains(
+            "\"part\": 1, \"status\": \"ok\", \"answer\": \"\\\"a\\\",b\", \"error\": null,"
+        ));
+        assert!(json.contains(
+            "\"part\": 2, \"status\": \"error\", \"answer\": null, \"error\": \"line 1, column 2:\\nexpected `\\\"`\","
+        ));
+
+        let csv = to_csv(&results);
+        assert!(csv.contains("\n2024,7,1,ok,\"\"\"a\"\",b\",,100,250,false,,,\n"));
+        assert!(csv.contains(
+            "\n2024,7,2,error,,\"line 1, column 2:\nexpected `\"\"`\",100,250,false,,,\n"
+        ));
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{
+    cache::{self, Entry},
+    cli::Selection,
+    memory::{self, Usage},
+    timing,
+};
+use aoc::{build_day, cancel::Token, input, params, Answer, AocError, Params, Solution};
+use std::{
+    collections::BTreeMap,
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone)]
+pub enum Outcome {
+    Solved(Answer),
+    Failed(String),
+    Panicked(String),
+    /// The part was cancelled when it ran out of time.
+    TimedOut,
+    Skipped,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(Answer::NotApplicable) => "n/a",
+            Outcome::Solved(_) => "ok",
+            Outcome::Failed(_) => "error",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut => "timeout",
+            Outcome::Skipped => "skipped",
+        }
+    }
+}
+
+pub struct PartResult {
+    pub outcome: Outcome,
+    pub time: Duration,
+    /// The part's memory usage, if it was measured.
+    pub memory: Option<Usage>,
+    /// Whether the answer and time come from the answer cache.
+    pub cached: bool,
+}
+
+impl PartResult {
+    fn skipped() -> PartResult {
+        PartResult {
+            outcome: Outcome::Skipped,
+            time: Duration::ZERO,
+            memory: None,
+            cached: false,
+        }
+    }
+
+    fn cached(entry: &Entry) -> PartResult {
+        PartResult {
+            outcome: Outcome::Solved(entry.answer.clone()),
+            time: entry.time,
+            memory: None,
+            cached: true,
+        }
+    }
+
+    /// The part's time, unless it was not run.
+    pub fn time(&self) -> Option<Duration> {
+        match self.outcome {
+            Outcome::Skipped => None,
+            _ => Some(self.time),
+        }
+    }
+}
+
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    /// Time spent in `AocDay::parse_with` turning the input text into the
+    /// day's input, after the text has been read. Days from the answer cache
+    /// report the time recorded when they were solved.
+    pub parse: Duration,
+    pub part1: PartResult,
+    pub part2: PartResult,
+}
+
+impl DayResult {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.time + self.part2.time
+    }
+
+    pub fn parts(&self) -> [(u8, &PartResult); 2] {
+        [(1, &self.part1), (2, &self.part2)]
+    }
+}
+
+/// How long a part may run by default before it is cancelled.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+static TIMEOUT: OnceLock<Option<Duration>> = OnceLock::new();
+
+/// Sets how long each part may run before it is cancelled, `None` for no
+/// limit. Can only be set once, before any day runs.
+pub fn set_timeout(timeout: Option<Duration>) {
+    TIMEOUT.set(timeout).expect("Timeout can only be set once");
+}
+
+pub fn timeout() -> Option<Duration> {
+    *TIMEOUT.get_or_init(|| Some(DEFAULT_TIMEOUT))
+}
+
+/// Reads and parses the selected day's input and runs the selected parts,
+/// timing the parse and each part. Errors and panics are reported per part; if
+/// the input cannot be read or parsed, every selected part fails with that
+/// error. Returns `None` if the day has no solution.
+///
+/// With the answer cache on, a day whose selected parts are all cached is not
+/// parsed or solved, and newly solved parts are added to the cache.
+pub fn run_day(selection: &Selection) -> Option<DayResult> {
+    let mut solution = build_day(selection.year, selection.day)?;
+    let text = input::read(selection.year, selection.day);
+    let params = params::config().params(selection.year, selection.day);
+
+    let keys = text
+        .as_ref()
+        .ok()
+        .and_then(|text| cache::keys(selection.year, selection.day, text, &params));
+    let cached = keys.map_or([None, None], |keys| keys.map(|key| cache::get(&key)));
+    if let Some(result) = cached_day(selection, &cached) {
+        return Some(result);
+    }
+
+    let name = input_name(selection);
+    let result = solve(solution.as_mut(), selection, &text, &name, &params);
+
+    if let Some(keys) = keys {
+        for ((_, part), key) in result.parts().into_iter().zip(keys) {
+            if let Outcome::Solved(answer) = &part.outcome {
+                let entry = Entry {
+                    answer: answer.clone(),
+                    time: part.time,
+                    parse: result.parse,
+                };
+                cache::insert(key, entry);
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Parses `text` and runs the selected parts like `run_day`, reporting parse
+/// errors against `name` and never using the answer cache.
+pub fn run_text(
+    solution: &mut dyn Solution,
+    selection: &Selection,
+    text: &str,
+    name: &str,
+) -> DayResult {
+    let params = params::config().params(selection.year, selection.day);
+    solve(solution, selection, &Ok(text.to_string()), name, &params)
+}
+
+/// Parses `text` into `solution` and times it, for callers that keep the
+/// parsed input to run parts on later with `run_parsed`.
+pub fn parse_text(
+    solution: &mut dyn Solution,
+    text: &str,
+    name: &str,
+    params: &Params,
+) -> (Result<(), Outcome>, Duration) {
+    let text = Ok(text.to_string());
+    let start = Instant::now();
+    let parsed = parse_input(solution, &text, name, params);
+    (parsed, start.elapsed())
+}
+
+/// Runs and times `part` of a solution whose input was parsed successfully.
+pub fn run_parsed(solution: &dyn Solution, part: u8) -> PartResult {
+    run_part(true, &Ok(()), || match part {
+        1 => solution.part1(),
+        _ => solution.part2(),
+    })
+}
+
+/// Parses the input and runs the selected parts, timing each step.
+fn solve(
+    solution: &mut dyn Solution,
+    selection: &Selection,
+    text: &io::Result<String>,
+    name: &str,
+    params: &Params,
+) -> DayResult {
+    let start = Instant::now();
+    let parsed = parse_input(solution, text, name, params);
+    let parse = start.elapsed();
+
+    DayResult {
+        year: selection.year,
+        day: selection.day,
+        parse,
+        part1: run_part(selection.part1, &parsed, || solution.part1()),
+        part2: run_part(selection.part2, &parsed, || solution.part2()),
+    }
+}
+
+/// Reads and parses the selected day's input without solving it. Returns
+/// `None` if the day has no solution.
+pub fn check_day(selection: &Selection) -> Option<Result<(), Outcome>> {
+    let mut solution = build_day(selection.year, selection.day)?;
+    let text = input::read(selection.year, selection.day);
+    let params = params::config().params(selection.year, selection.day);
+    Some(parse_input(
+        solution.as_mut(),
+        &text,
+        &input_name(selection),
+        &params,
+    ))
+}
+
+/// Parses the day's input text, reporting errors against the input's `name`
+/// and turning a panic into an outcome.
+fn parse_input(
+    solution: &mut dyn Solution,
+    text: &io::Result<String>,
+    name: &str,
+    params: &Params,
+) -> Result<(), Outcome> {
+    match text {
+        Ok(text) => guarded(|| solution.parse(text, params).map_err(|e| e.with_file(name))),
+        Err(e) => Err(Outcome::Failed(e.to_string())),
+    }
+}
+
+/// The selected day from the answer cache, if every selected part is cached.
+fn cached_day(selection: &Selection, cached: &[Option<Entry>; 2]) -> Option<DayResult> {
+    let [part1, part2] = cached;
+    let part = |selected: bool, entry: &Option<Entry>| match (selected, entry) {
+        (false, _) => Some(PartResult::skipped()),
+        (true, Some(entry)) => Some(PartResult::cached(entry)),
+        (true, None) => None,
+    };
+
+    Some(DayResult {
+        year: selection.year,
+        day: selection.day,
+        parse: cached.iter().flatten().next()?.parse,
+        part1: part(selection.part1, part1)?,
+        part2: part(selection.part2, part2)?,
+    })
+}
+
+/// Runs the selected days on up to `jobs` worker threads and hands each result
+/// to `report` in the order of `days`, as soon as all earlier days are done.
+pub fn run_days(
+    days: &[Selection],
+    jobs: usize,
+    mut report: impl FnMut(&Selection, Option<DayResult>),
+) {
+    if jobs <= 1 {
+        for selection in days {
+            report(selection, run_day(selection));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(selection) = days.get(index) else {
+                    break;
+                };
+                if sender.send((index, run_day(selection))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&printed) {
+                report(&days[printed], result);
+                printed += 1;
+            }
+        }
+    });
+}
+
+/// The name parse errors are reported against.
+fn input_name(selection: &Selection) -> String {
+    match input::path(selection.year, selection.day) {
+        Some(path) => path.display().to_string(),
+        None => "<stdin>".to_string(),
+    }
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs and times a part if it is selected and the input was parsed. A part
+/// that fails after running out of time has timed out; parts only stop early
+/// where their long loops check for cancellation.
+fn run_part(
+    selected: bool,
+    parsed: &Result<(), Outcome>,
+    solve: impl FnOnce() -> Result<Answer, AocError>,
+) -> PartResult {
+    if !selected {
+        return PartResult::skipped();
+    }
+    if let Err(outcome) = parsed {
+        return PartResult {
+            outcome: outcome.clone(),
+            time: Duration::ZERO,
+            memory: None,
+            cached: false,
+        };
+    }
+
+    let token = timeout().map_or_else(Token::new, Token::with_timeout);
+    let start = Instant::now();
+    let (solved, memory) = memory::measure(|| token.install(|| guarded(solve)));
+    let time = start.elapsed();
+    let outcome = match solved {
+        Ok(answer) => Outcome::Solved(answer),
+        Err(Outcome::Failed(_)) if token.is_cancelled() => Outcome::TimedOut,
+        Err(outcome) => outcome,
+    };
+    PartResult {
+        outcome,
+        time,
+        memory,
+        cached: false,
+    }
+}
+
+/// Runs `part` with the day's reference solver on the parsed input, timed and
+/// cancelled like a fast part. Returns `None` if the day has no reference
+/// solver for the part.
+pub fn run_reference(solution: &dyn Solution, part: u8) -> Option<PartResult> {
+    let mut missing = false;
+    let result = run_part(true, &Ok(()), || {
+        solution.reference(part).unwrap_or_else(|| {
+            missing = true;
+            Ok(Answer::NotApplicable)
+        })
+    });
+    (!missing).then_some(result)
+}
+
+/// Runs `f`, turning both its error and a panic into a failed `Outcome`.
+fn guarded<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, Outcome> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(Outcome::Failed(e.to_string())),
+        Err(payload) => Err(Outcome::Panicked(panic_message(payload.as_ref()))),
+    }
+}
+
+pub fn print_answers(result: &DayResult) {
+    println!("--- Day {:02} ---", result.day);
+    for (part, result) in result.parts() {
+        if let Some(line) = answer_line(part, result) {
+            println!("{}", line);
+        }
+    }
+}
+
+/// The line `print_answers` shows for a part, `None` if it was skipped.
+pub fn answer_line(part: u8, result: &PartResult) -> Option<String> {
+    Some(match &result.outcome {
+        Outcome::Solved(answer) if result.cached => format!("Part {}: {} (cached)", part, answer),
+        Outcome::Solved(answer) => format!("Part {}: {}", part, answer),
+        Outcome::Failed(message) => format!("Part {}: error: {}", part, message),
+        Outcome::Panicked(message) => format!("Part {}: panicked: {}", part, message),
+        Outcome::TimedOut => format!(
+            "Part {}: timed out after {}",
+            part,
+            timing::format_duration(result.time)
+        ),
+        Outcome::Skipped => return None,
+    })
+}
@@ -0,0 +1,220 @@
+use aoc::input;
+use std::{fs, path::Path};
+
+/// The crate the binary was built from; `new` edits its sources.
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Creates `src/yYYYY/dayNN.rs` from the day template and an empty input
+/// file, and registers the day in the year's `days!` list. A new year gets its
+/// module and a `years!` entry in `src/lib.rs` first. Nothing is written if the
+/// day already exists.
+pub fn create_day(year: u16, day: u8) -> Result<(), String> {
+    let root = Path::new(CRATE_DIR);
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let source = year_dir.join(format!("day{:02}.rs", day));
+    let year_mod = year_dir.join("mod.rs");
+    let input = input::year_dir(&root.join(input::DEFAULT_DIR), year).join(input::file_name(day));
+    let lib = root.join("src").join("lib.rs");
+
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+
+    let year_source = if year_mod.exists() {
+        read(&year_mod)?
+    } else {
+        let lib_source = register(&read(&lib)?, "years!", year, &year_entry(year))?;
+        fs::create_dir_all(&year_dir)
+            .map_err(|e| format!("cannot create {}: {}", year_dir.display(), e))?;
+        write(&lib, &lib_source)?;
+        println!("Registered {} in {}", year, lib.display());
+        format!("//! Advent of Code {}.\n\ndays! {{\n}}\n", year)
+    };
+    let year_source = register(&year_source, "days!", day.into(), &day_entry(day))?;
+
+    write(&source, &template(day))?;
+    write(&year_mod, &year_source)?;
+    println!("Created {}", source.display());
+    println!("Registered day {} in {}", day, year_mod.display());
+
+    if input.exists() {
+        println!("Kept existing {}", input.display());
+    } else {
+        fs::create_dir_all(input.parent().unwrap_or(root))
+            .map_err(|e| format!("cannot create {}: {}", input.display(), e))?;
+        write(&input, "")?;
+        println!("Created {}", input.display());
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+fn year_entry(year: u16) -> String {
+    format!("    {} => y{},", year, year)
+}
+
+fn day_entry(day: u8) -> String {
+    format!("    {} => day{:02}::AocDay{},", day, day, day)
+}
+
+/// Inserts `entry`, registered under `number`, into the `macro_name` list of
+/// `source`, keeping the list ordered by number.
+fn register(source: &str, macro_name: &str, number: u16, entry: &str) -> Result<String, String> {
+    let header = format!("{} {{", macro_name);
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| *line == header)
+        .ok_or_else(|| format!("cannot find the `{}` list", macro_name))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or_else(|| format!("cannot find the end of the `{}` list", macro_name))?;
+
+    let mut insert_at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let Some(Ok(registered)) = line
+            .split_once("=>")
+            .map(|(number, _)| number.trim().parse::<u16>())
+        else {
+            continue;
+        };
+        if registered == number {
+            return Err(format!(
+                "{} is already registered in `{}`",
+                number, macro_name
+            ));
+        }
+        if registered > number && insert_at == end {
+            insert_at = i;
+        }
+    }
+
+    lines.insert(insert_at, entry);
+
+    let mut out = lines.join("\n");
+    if source.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn template(day: u8) -> String {
+    TEMPLATE
+        .replace("{NAME}", &format!("AocDay{}", day))
+        .trim_start()
+        .to_string()
+}
+
+const TEMPLATE: &str = r#"
+use crate::{utils::lines, Answer, AocDay, AocError};
+
+pub struct {NAME} {
+    pub lines: Vec<String>,
+}
+
+impl {NAME} {
+    pub fn new(input: &str) -> Result<{NAME}, AocError> {
+        Ok({NAME} {
+            lines: lines(input),
+        })
+    }
+
+    pub fn part1(&self) -> Result<usize, AocError> {
+        Err(AocError::new("not solved yet"))
+    }
+
+    pub fn part2(&self) -> Result<usize, AocError> {
+        Err(AocError::new("not solved yet"))
+    }
+}
+
+impl AocDay for {NAME} {
+    type Input = {NAME};
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        {NAME}::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "add the example from the puzzle text"]
+    fn part1_example() {
+        check_part1::<{NAME}>(EXAMPLE, 0);
+    }
+
+    #[test]
+    #[ignore = "add the example from the puzzle text"]
+    fn part2_example() {
+        check_part2::<{NAME}>(EXAMPLE, 0);
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: &str = "\
+//! Advent of Code 2024.
+
+days! {
+    1 => day01::AocDay1,
+    3 => day03::AocDay3,
+}
+";
+
+    #[test]
+    fn registers_day_in_order() {
+        let year = register(YEAR, "days!", 2, &day_entry(2)).unwrap();
+        assert!(year.contains(
+            "    1 => day01::AocDay1,\n    2 => day02::AocDay2,\n    3 => day03::AocDay3,\n}"
+        ));
+
+        let year = register(YEAR, "days!", 25, &day_entry(25)).unwrap();
+        assert!(year.contains("    3 => day03::AocDay3,\n    25 => day25::AocDay25,\n}"));
+    }
+
+    #[test]
+    fn registers_day_in_empty_list() {
+        let year = register("days! {\n}\n", "days!", 1, &day_entry(1)).unwrap();
+        assert_eq!(year, "days! {\n    1 => day01::AocDay1,\n}\n");
+    }
+
+    #[test]
+    fn template_reports_unsolved_parts() {
+        let source = template(7);
+        assert!(source.contains("pub struct AocDay7 {"));
+        assert!(!source.contains("todo!"));
+    }
+
+    #[test]
+    fn rejects_registered_day() {
+        assert!(register(YEAR, "days!", 3, &day_entry(3)).is_err());
+    }
+}
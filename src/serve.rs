@@ -0,0 +1,322 @@
+//! A results dashboard over HTTP: one page listing every selected day's
+//! answers, timings and verification status, and `POST /run/DAY` to solve a
+//! day again. Uses a plain `TcpListener`, one thread per connection.
+
+use crate::{
+    cli::Selection,
+    runner::{self, DayResult},
+    timing,
+    verify::{self, Answers, Status},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    sync::Mutex,
+    thread,
+    time::Duration,
+};
+
+pub const DEFAULT_PORT: u16 = 2024;
+
+/// How long a connection may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The latest result of every served day, `None` if it has no solution.
+type Results = BTreeMap<u8, Option<DayResult>>;
+
+/// Solves the selected days, then serves their results on localhost until the
+/// process is stopped. Only returns if the port cannot be bound.
+pub fn run(days: &[Selection], jobs: usize, answers: &Answers, port: u16) -> Result<(), String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|e| format!("cannot listen on port {}: {}", port, e))?;
+
+    println!("Solving {} days", days.len());
+    let mut results = Results::new();
+    runner::run_days(days, jobs, |selection, result| {
+        results.insert(selection.day, result);
+    });
+    let results = Mutex::new(results);
+    println!("Serving the results on http://localhost:{}/", port);
+
+    thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let results = &results;
+            scope.spawn(move || {
+                if let Err(e) = handle(stream, days, answers, results) {
+                    eprintln!("warning: request failed: {}", e);
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+/// Answers one request and closes the connection.
+fn handle(
+    mut stream: TcpStream,
+    days: &[Selection],
+    answers: &Answers,
+    results: &Mutex<Results>,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    // Nothing in the headers or a body matters here, but reading them keeps
+    // the client from seeing a reset connection.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+
+    let mut fields = request.split_whitespace();
+    let (method, path) = (fields.next().unwrap_or(""), fields.next().unwrap_or(""));
+    let response = match (method, path) {
+        ("GET", "/") => {
+            let page = page(days, answers, &results.lock().unwrap());
+            Response::ok("text/html; charset=utf-8", page)
+        }
+        (_, "/") => Response::status("405 Method Not Allowed"),
+        (_, path) => match run_path(path, days) {
+            Some(selection) if method == "POST" => {
+                let result = runner::run_day(selection);
+                results.lock().unwrap().insert(selection.day, result);
+                Response::redirect(&format!("/#day{:02}", selection.day))
+            }
+            Some(_) => Response::status("405 Method Not Allowed"),
+            None => Response::status("404 Not Found"),
+        },
+    };
+    response.write(&mut stream)
+}
+
+/// The served day a `/run/DAY` path re-runs.
+fn run_path<'a>(path: &str, days: &'a [Selection]) -> Option<&'a Selection> {
+    let day: u8 = path.strip_prefix("/run/")?.parse().ok()?;
+    days.iter().find(|selection| selection.day == day)
+}
+
+struct Response {
+    status: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Response {
+    fn ok(content_type: &str, body: String) -> Response {
+        Response {
+            status: "200 OK",
+            headers: vec![("Content-Type", content_type.to_string())],
+            body,
+        }
+    }
+
+    fn redirect(location: &str) -> Response {
+        Response {
+            status: "303 See Other",
+            headers: vec![("Location", location.to_string())],
+            body: String::new(),
+        }
+    }
+
+    fn status(status: &'static str) -> Response {
+        Response {
+            status,
+            headers: vec![("Content-Type", "text/plain; charset=utf-8".to_string())],
+            body: format!("{}\n", status),
+        }
+    }
+
+    fn write(&self, stream: &mut impl Write) -> io::Result<()> {
+        let mut head = format!("HTTP/1.1 {}\r\n", self.status);
+        for (name, value) in &self.headers {
+            write!(head, "{}: {}\r\n", name, value).unwrap();
+        }
+        write!(
+            head,
+            "Content-Length: {}\r\nConnection: close\r\n\r\n",
+            self.body.len()
+        )
+        .unwrap();
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(self.body.as_bytes())?;
+        stream.flush()
+    }
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: left; }
+td.time { text-align: right; font-variant-numeric: tabular-nums; }
+.pass { color: #080; } .fail { color: #c00; } .none { color: #888; }";
+
+/// The dashboard: one row per part, with the day's re-run button spanning its
+/// rows.
+fn page(days: &[Selection], answers: &Answers, results: &Results) -> String {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut rows = String::new();
+
+    for selection in days {
+        let label = format!("{:02}", selection.day);
+        let Some(Some(result)) = results.get(&selection.day) else {
+            failed += 1;
+            writeln!(
+                rows,
+                "<tr id=\"day{0}\"><td>{0}</td><td>-</td><td class=\"fail\">FAIL</td><td>not implemented</td><td></td><td></td></tr>",
+                label
+            )
+            .unwrap();
+            continue;
+        };
+
+        let parts: Vec<_> = result
+            .parts()
+            .into_iter()
+            .filter_map(|(part, part_result)| {
+                let (status, details) =
+                    verify::check(part_result, answers.get(&(result.day, part)))?;
+                Some((part, part_result.time, status, details))
+            })
+            .collect();
+        for (i, (part, time, status, details)) in parts.iter().enumerate() {
+            let class = match status {
+                Status::Pass => {
+                    passed += 1;
+                    "pass"
+                }
+                Status::Fail => {
+                    failed += 1;
+                    "fail"
+                }
+                Status::None | Status::NotApplicable => "none",
+            };
+            // The first row of a day is the target of its link and holds
+            // the button.
+            let (id, button) = if i == 0 {
+                (
+                    format!(" id=\"day{}\"", label),
+                    format!(
+                        "<td rowspan=\"{}\"><form method=\"post\" action=\"/run/{}\"><button>Run again</button></form></td>",
+                        parts.len(),
+                        selection.day
+                    ),
+                )
+            } else {
+                (String::new(), String::new())
+            };
+            writeln!(
+                rows,
+                "<tr{}><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{}</td><td class=\"time\">{}</td>{}</tr>",
+                id,
+                label,
+                part,
+                class,
+                status,
+                escape(details),
+                timing::format_duration(*time),
+                button
+            )
+            .unwrap();
+        }
+    }
+
+    let year = days.first().map_or(String::new(), |s| s.year.to_string());
+    let total: Duration = results.values().flatten().map(DayResult::total).sum();
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code {0}</title>
+<style>
+{1}
+</style>
+</head>
+<body>
+<h1>Advent of Code {0}</h1>
+<p>{2} passed, {3} failed, {4} in total</p>
+<table>
+<tr><th>Day</th><th>Part</th><th>Status</th><th>Details</th><th>Time</th><th></th></tr>
+{5}</table>
+</body>
+</html>
+",
+        year,
+        STYLE,
+        passed,
+        failed,
+        timing::format_duration(total),
+        rows
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Outcome, PartResult};
+    use aoc::Answer;
+
+    fn part(outcome: Outcome) -> PartResult {
+        PartResult {
+            outcome,
+            time: Duration::from_millis(1),
+            memory: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn page_verifies_and_escapes_answers() {
+        let days = [Selection::all(2024, 1), Selection::all(2024, 2)];
+        let mut results = Results::new();
+        results.insert(
+            1,
+            Some(DayResult {
+                year: 2024,
+                day: 1,
+                parse: Duration::ZERO,
+                part1: part(Outcome::Solved(Answer::Integer(11))),
+                part2: part(Outcome::Failed("expected `<`".to_string())),
+            }),
+        );
+        results.insert(2, None);
+        let answers = Answers::from([((1, 1), Answer::Integer(11))]);
+
+        let page = page(&days, &answers, &results);
+        assert!(page.contains("<p>1 passed, 2 failed, "));
+        assert!(page.contains("<td class=\"pass\">PASS</td><td>11</td>"));
+        assert!(page.contains("error: expected `&lt;`"));
+        assert!(page.contains("action=\"/run/1\""));
+        assert!(page.contains("<tr id=\"day02\">"));
+    }
+
+    #[test]
+    fn run_path_accepts_served_days_only() {
+        let days = [Selection::all(2024, 5)];
+        assert_eq!(run_path("/run/5", &days), Some(&days[0]));
+        assert_eq!(run_path("/run/6", &days), None);
+        assert_eq!(run_path("/run/x", &days), None);
+        assert_eq!(run_path("/5", &days), None);
+    }
+}
@@ -0,0 +1,292 @@
+//! Stress testing: solving random inputs from each day's generator and
+//! reporting the inputs on which a part panics, fails or runs out of time,
+//! or, when cross-checking, gives another answer than the day's reference
+//! solver.
+
+use crate::{
+    cli::Selection,
+    runner::{self, DayResult, Outcome, PartResult},
+    timing,
+};
+use aoc::{build_day, params, random::Rng, Answer};
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const FAILURES_DIR: &str = "stress_failures";
+
+/// How long a part may run by default when stress testing.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The default input size for stress testing.
+pub const DEFAULT_SIZE: usize = 20;
+
+/// The default input size for cross-checking, small enough for the reference
+/// solvers.
+pub const CROSSCHECK_SIZE: usize = 6;
+
+/// How long after its parts should have timed out a case is given up as hung,
+/// which happens when a loop never checks for cancellation.
+const GRACE: Duration = Duration::from_secs(5);
+
+#[derive(Debug, PartialEq)]
+pub struct StressOptions {
+    /// Inputs generated per day.
+    pub cases: usize,
+    /// The size passed to the generators, `DEFAULT_SIZE` or `CROSSCHECK_SIZE`
+    /// if not given.
+    pub size: Option<usize>,
+    /// The seed of the first case, random if not given.
+    pub seed: Option<u64>,
+}
+
+impl Default for StressOptions {
+    fn default() -> Self {
+        StressOptions {
+            cases: 20,
+            size: None,
+            seed: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only look for parts that panic, fail or time out.
+    Stress,
+    /// Also compare each answer with the day's reference solver.
+    Crosscheck,
+}
+
+impl Mode {
+    fn command(self) -> &'static str {
+        match self {
+            Mode::Stress => "stress",
+            Mode::Crosscheck => "crosscheck",
+        }
+    }
+}
+
+/// Solves `options.cases` generated inputs per selected day and lists the
+/// failures, saving their inputs to `stress_failures/`. Returns `false` if any
+/// case failed.
+pub fn run(days: &[Selection], options: &StressOptions, mode: Mode) -> bool {
+    let seed = options.seed.unwrap_or_else(random_seed);
+    let size = options.size.unwrap_or(match mode {
+        Mode::Stress => DEFAULT_SIZE,
+        Mode::Crosscheck => CROSSCHECK_SIZE,
+    });
+    println!(
+        "Seed {}, {} cases of size {} per day\n",
+        seed, options.cases, size
+    );
+    // Failures are listed with their messages, so keep panics off stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    println!("{:<5} {:<6} Failed", "Day", "Cases");
+    let mut cases = 0;
+    let mut failed = 0;
+    for selection in days {
+        let (day_cases, day_failed) = stress_day(selection, options.cases, size, seed, mode);
+        cases += day_cases;
+        failed += day_failed;
+    }
+
+    println!("\n{} cases, {} failed", cases, failed);
+    if failed > 0 {
+        println!(
+            "Rerun a case with `aoc {} DAY --seed SEED --cases 1`, or its input with `aoc DAY --input FILE`",
+            mode.command()
+        );
+    }
+    failed == 0
+}
+
+/// Runs and prints one day's cases, returning how many ran and failed. The
+/// first case that hangs ends the day, as its thread cannot be stopped.
+/// Cross-checking a day without reference solvers stops after its first case.
+fn stress_day(
+    selection: &Selection,
+    cases_per_day: usize,
+    size: usize,
+    seed: u64,
+    mode: Mode,
+) -> (usize, usize) {
+    let label = format!("{:02}", selection.day);
+    let Some(solution) = build_day(selection.year, selection.day) else {
+        println!("{:<5} not implemented", label);
+        return (0, 0);
+    };
+
+    let mut cases = 0;
+    let mut failures = Vec::new();
+    let mut notes = Vec::new();
+    for case in 0..cases_per_day {
+        let case_seed = seed.wrapping_add(case as u64);
+        let params = params::config().params(selection.year, selection.day);
+        let mut rng = Rng::new(case_seed);
+        let generated = panic::catch_unwind(AssertUnwindSafe(|| {
+            solution.generate(&mut rng, size, &params)
+        }));
+
+        cases += 1;
+        let text = match generated {
+            Ok(Some(text)) => text,
+            Ok(None) => {
+                println!("{:<5} no generator", label);
+                return (0, 0);
+            }
+            Err(payload) => {
+                let message = runner::panic_message(payload.as_ref());
+                failures.push(format!(
+                    "seed {}: generator panicked: {}",
+                    case_seed, message
+                ));
+                continue;
+            }
+        };
+
+        let path =
+            Path::new(FAILURES_DIR).join(format!("day{:02}-{}.txt", selection.day, case_seed));
+        let Some((result, references)) = solve_case(selection, &text, &path, mode) else {
+            failures.push(format!(
+                "seed {}: hung, skipping the remaining cases ({})",
+                case_seed,
+                save(&path, &text)
+            ));
+            break;
+        };
+
+        if mode == Mode::Crosscheck && references.iter().all(Option::is_none) {
+            println!("{:<5} no reference solver", label);
+            return (0, 0);
+        }
+
+        let mut problems = Vec::new();
+        for ((part, part_result), reference) in result.parts().into_iter().zip(&references) {
+            if let Some(problem) = problem(part_result) {
+                problems.push(format!("part {} {}", part, problem));
+            }
+            let (Outcome::Solved(answer), Some(reference)) = (&part_result.outcome, reference)
+            else {
+                continue;
+            };
+            match &reference.outcome {
+                Outcome::Solved(expected) if expected == answer => {}
+                Outcome::Solved(expected) => problems.push(format!(
+                    "part {} answered {}, the reference {}",
+                    part, answer, expected
+                )),
+                // A reference too slow for the input says nothing about the
+                // answer.
+                Outcome::TimedOut => notes.push(format!(
+                    "seed {}: part {} reference timed out after {}, not compared",
+                    case_seed,
+                    part,
+                    timing::format_duration(reference.time)
+                )),
+                _ => {
+                    let problem = problem(reference).unwrap_or_default();
+                    problems.push(format!("part {} reference {}", part, problem));
+                }
+            }
+        }
+        if !problems.is_empty() {
+            failures.push(format!(
+                "seed {}: {} ({})",
+                case_seed,
+                problems.join("; "),
+                save(&path, &text)
+            ));
+        }
+    }
+
+    println!("{:<5} {:<6} {}", label, cases, failures.len());
+    for line in failures.iter().chain(&notes) {
+        println!("      {}", line);
+    }
+    (cases, failures.len())
+}
+
+/// The reference solvers' results of both parts, `None` where a part has no
+/// reference solver or was not compared.
+type References = [Option<PartResult>; 2];
+
+/// Solves a generated input on its own thread, and when cross-checking runs
+/// the reference solvers on every solved part. Returns `None` if the case has
+/// not finished well after its parts should have timed out. Parse errors point
+/// at `path`, where the input is saved if it fails.
+fn solve_case(
+    selection: &Selection,
+    text: &str,
+    path: &Path,
+    mode: Mode,
+) -> Option<(DayResult, References)> {
+    let (sender, receiver) = mpsc::channel();
+    let selection = *selection;
+    let text = text.to_string();
+    let name = path.display().to_string();
+
+    thread::spawn(move || {
+        if let Some(mut solution) = build_day(selection.year, selection.day) {
+            let result = runner::run_text(solution.as_mut(), &selection, &text, &name);
+            let mut references = [None, None];
+            if mode == Mode::Crosscheck {
+                for ((part, part_result), reference) in
+                    result.parts().into_iter().zip(&mut references)
+                {
+                    if let Outcome::Solved(answer) = &part_result.outcome {
+                        if *answer != Answer::NotApplicable {
+                            *reference = runner::run_reference(solution.as_ref(), part);
+                        }
+                    }
+                }
+            }
+            let _ = sender.send((result, references));
+        }
+    });
+
+    // The fast and the reference solver each get the timeout per part.
+    let parts = if mode == Mode::Crosscheck { 4 } else { 2 };
+    match runner::timeout() {
+        Some(timeout) => receiver.recv_timeout(timeout * parts + GRACE).ok(),
+        None => receiver.recv().ok(),
+    }
+}
+
+/// What went wrong in a part, if anything.
+fn problem(result: &PartResult) -> Option<String> {
+    match &result.outcome {
+        Outcome::Solved(_) | Outcome::Skipped => None,
+        Outcome::Failed(message) => Some(format!("error: {}", message)),
+        Outcome::Panicked(message) => Some(format!("panicked: {}", message)),
+        Outcome::TimedOut => Some(format!(
+            "timed out after {}",
+            timing::format_duration(result.time)
+        )),
+    }
+}
+
+/// Saves a failing input, returning where it went or why it could not be
+/// saved.
+fn save(path: &Path, text: &str) -> String {
+    let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, text));
+    match saved {
+        Ok(()) => path.display().to_string(),
+        Err(e) => format!("cannot write {}: {}", path.display(), e),
+    }
+}
+
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_nanos() as u64)
+}
@@ -0,0 +1,70 @@
+use crate::runner::{DayResult, PartResult};
+use std::time::Duration;
+
+const BAR_WIDTH: usize = 30;
+
+/// Phases at least this long are flagged in the report.
+const SLOW: Duration = Duration::from_secs(1);
+
+pub fn print_report(results: &[DayResult]) {
+    let total = results.iter().map(DayResult::total).sum::<Duration>();
+
+    println!();
+    println!(
+        "{:<5} {:>10} {:>10}   {:>10}   {:>10}  Share",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    for result in results {
+        let day_total = result.total();
+        let line = format!(
+            "{:<5} {:>10} {} {} {:>10}  {}",
+            format!("{:02}", result.day),
+            format_duration(result.parse),
+            format_part(&result.part1),
+            format_part(&result.part2),
+            format_duration(day_total),
+            bar(day_total, total),
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!(
+        "{:<5} {:>10} {:>10}   {:>10}   {:>10}",
+        "Total",
+        format_duration(results.iter().map(|r| r.parse).sum()),
+        format_duration(results.iter().map(|r| r.part1.time).sum()),
+        format_duration(results.iter().map(|r| r.part2.time).sum()),
+        format_duration(total),
+    );
+}
+
+/// Formats a part's time in a fixed-width column, flagging slow parts with `!`.
+fn format_part(part: &PartResult) -> String {
+    match part.time() {
+        Some(time) => {
+            let marker = if time >= SLOW { "!" } else { "" };
+            format!("{:>10} {:1}", format_duration(time), marker)
+        }
+        None => format!("{:>10}  ", "-"),
+    }
+}
+
+fn bar(value: Duration, total: Duration) -> String {
+    if total.is_zero() {
+        return String::new();
+    }
+    let width = (value.as_secs_f64() / total.as_secs_f64() * BAR_WIDTH as f64).round() as usize;
+    "#".repeat(width)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2} s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2} ms", secs * 1e3)
+    } else {
+        format!("{:.2} µs", secs * 1e6)
+    }
+}
@@ -1,46 +1,147 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-pub fn read_lines(file_path: &str) -> Vec<String> {
-    let file = File::open(file_path).expect("File not found");
-    let reader = BufReader::new(file);
-    let mut lines = Vec::new();
-    for line in reader.lines() {
-        lines.push(line.unwrap());
-    }
-    lines
+use crate::AocError;
+use std::{any, str::FromStr};
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
 }
 
-pub fn read_matrix(file_path: &str) -> Vec<Vec<char>> {
-    read_lines(file_path)
+/// Reads a rectangular grid of characters. Trailing blank lines are ignored.
+pub fn matrix(input: &str) -> Result<Vec<Vec<char>>, AocError> {
+    let mut matrix = lines(input)
         .iter()
-        .map(|line| line.chars().collect())
-        .collect()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    while matrix.last().is_some_and(|row| row.is_empty()) {
+        matrix.pop();
+    }
+
+    if matrix.is_empty() {
+        return Err(AocError::new("expected a grid, found an empty input"));
+    }
+
+    let width = matrix[0].len();
+    if let Some((r, row)) = matrix
+        .iter()
+        .enumerate()
+        .find(|(_, row)| row.len() != width)
+    {
+        return Err(AocError::at(
+            r,
+            row.len().min(width),
+            format!(
+                "expected a row of {} cells like the first one, found {}",
+                width,
+                row.len()
+            ),
+        ));
+    }
+
+    Ok(matrix)
 }
 
-pub fn read_num_matrix(file_path: &str) -> Vec<Vec<i32>> {
-    read_lines(file_path)
+pub fn num_matrix(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    let matrix = matrix(input)?;
+    matrix
         .iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as i32)
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(c, &cell)| {
+                    cell.to_digit(10).map(|d| d as i32).ok_or_else(|| {
+                        AocError::at(r, c, format!("expected a digit, found `{}`", cell))
+                    })
+                })
                 .collect()
         })
         .collect()
 }
 
-pub fn in_bounds<T>(matrix: &Vec<Vec<T>>, r: isize, c: isize) -> bool {
+/// The 0-based column at which `field`, a slice of `line`, starts.
+pub fn column(line: &str, field: &str) -> usize {
+    let offset = (field.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() && line.is_char_boundary(offset) {
+        line[..offset].chars().count()
+    } else {
+        0
+    }
+}
+
+/// Parses `field`, a slice of the `row`th line, reporting where it is if it
+/// is not a valid `T`.
+pub fn parse_field<T: FromStr>(row: usize, line: &str, field: &str) -> Result<T, AocError> {
+    field.trim().parse().map_err(|_| {
+        let kind = any::type_name::<T>();
+        AocError::at(
+            row,
+            column(line, field),
+            format!("expected {}, found `{}`", kind, field.trim()),
+        )
+    })
+}
+
+/// Parses every field of the `row`th line yielded by `fields`.
+pub fn parse_fields<'a, T: FromStr>(
+    row: usize,
+    line: &str,
+    fields: impl Iterator<Item = &'a str>,
+) -> Result<Vec<T>, AocError> {
+    fields.map(|field| parse_field(row, line, field)).collect()
+}
+
+/// Collects exactly `N` fields split from `text`, a slice of the `row`th line.
+pub fn fields<'a, const N: usize>(
+    row: usize,
+    line: &str,
+    text: &str,
+    fields: impl Iterator<Item = &'a str>,
+) -> Result<[&'a str; N], AocError> {
+    let fields = fields.collect::<Vec<_>>();
+    let found = fields.len();
+    fields.try_into().map_err(|_| {
+        AocError::at(
+            row,
+            column(line, text),
+            format!("expected {} fields, found {} in `{}`", N, found, text),
+        )
+    })
+}
+
+/// Strips `prefix` from `field`, a slice of the `row`th line.
+pub fn strip_prefix<'a>(
+    row: usize,
+    line: &str,
+    field: &'a str,
+    prefix: &str,
+) -> Result<&'a str, AocError> {
+    field.strip_prefix(prefix).ok_or_else(|| {
+        AocError::at(
+            row,
+            column(line, field),
+            format!("expected `{}`, found `{}`", prefix, field),
+        )
+    })
+}
+
+pub fn in_bounds<T>(matrix: &[Vec<T>], r: isize, c: isize) -> bool {
     r >= 0 && (r as usize) < matrix.len() && c >= 0 && (c as usize) < matrix[0].len()
 }
 
-pub fn on_edge<T>(matrix: &Vec<Vec<T>>, row: isize, col: isize) -> bool {
+pub fn on_edge<T>(matrix: &[Vec<T>], row: isize, col: isize) -> bool {
     row == 0 || row == matrix.len() as isize - 1 || col == 0 || col == matrix[0].len() as isize - 1
 }
 
-pub fn split_lines_whitespace(file_path: &str) -> (Vec<String>, Vec<String>) {
-    let lines = read_lines(file_path);
+/// Splits the input at its first blank line into the lines before and after
+/// it, failing if there is no blank line.
+pub fn split_lines_whitespace(input: &str) -> Result<(Vec<String>, Vec<String>), AocError> {
+    let lines = lines(input);
+    if !lines.iter().any(|line| line.is_empty()) {
+        return Err(AocError::at_row(
+            lines.len(),
+            "expected a blank line between the two sections, found none",
+        ));
+    }
+
     let mut first = Vec::new();
     let mut second = Vec::new();
     let mut first_done = false;
@@ -55,11 +156,11 @@ pub fn split_lines_whitespace(file_path: &str) -> (Vec<String>, Vec<String>) {
             second.push(line);
         }
     }
-    (first, second)
+    Ok((first, second))
 }
 
-pub fn split_multi_lines_whitespace(file_path: &str) -> Vec<Vec<String>> {
-    let lines = read_lines(file_path);
+pub fn split_multi_lines_whitespace(input: &str) -> Vec<Vec<String>> {
+    let lines = lines(input);
     let mut current = Vec::new();
     let mut result = Vec::new();
     for line in lines {
@@ -75,3 +176,25 @@ pub fn split_multi_lines_whitespace(file_path: &str) -> Vec<Vec<String>> {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_rejects_ragged_rows() {
+        assert_eq!(matrix("ab\ncd\n\n").unwrap().len(), 2);
+        let error = matrix("abc\nab\nabc").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn split_lines_whitespace_needs_a_blank_line() {
+        let (first, second) = split_lines_whitespace("a\nb\n\nc\n").unwrap();
+        assert_eq!((first.len(), second.len()), (2, 1));
+        assert_eq!(
+            split_lines_whitespace("a\nb\n").unwrap_err().line(),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    cli::{Selection, FIRST_DAY, LAST_DAY},
+    runner::{self, Outcome, PartResult},
+    timing,
+};
+use aoc::Answer;
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const ANSWERS_DIR: &str = "answers";
+
+/// `answers/YYYY.txt`, the default answers file of `year`.
+pub fn default_answers(year: u16) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("{}.txt", year))
+}
+
+/// Answers keyed by `(day, part)`.
+pub type Answers = HashMap<(u8, u8), Answer>;
+
+/// Reads an answers file with one `day part answer` line per answer. The
+/// answer is the rest of the line and may contain spaces. Blank lines and
+/// lines starting with `#` are ignored.
+pub fn load_answers(path: &Path) -> io::Result<Answers> {
+    parse_answers(path, &fs::read_to_string(path)?)
+}
+
+/// Parses the contents of the answers file at `path`, which errors name.
+fn parse_answers(path: &Path, contents: &str) -> io::Result<Answers> {
+    let mut answers = Answers::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let (Some(day), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid(path, i, "expected `day part answer`"));
+        };
+
+        let day = match day.parse() {
+            Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => day,
+            _ => return Err(invalid(path, i, "invalid day")),
+        };
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(invalid(path, i, "invalid part")),
+        };
+        let Ok(answer) = answer.trim().parse();
+        answers.insert((day, part), answer);
+    }
+
+    Ok(answers)
+}
+
+fn invalid(path: &Path, line: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}:{}: {}", path.display(), line + 1, message),
+    )
+}
+
+/// Runs the selected days, compares every answer with the expected one and
+/// prints a table. Returns `false` if any answer does not match.
+pub fn run(days: &[Selection], jobs: usize, answers: &Answers) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+
+    println!("{:<5} {:<5} {:<8} Details", "Day", "Part", "Status");
+
+    runner::run_days(days, jobs, |selection, result| {
+        let Some(result) = result else {
+            println!(
+                "{:<5} {:<5} {:<8} not implemented",
+                format!("{:02}", selection.day),
+                "-",
+                "FAIL"
+            );
+            failed += 1;
+            return;
+        };
+
+        for (part, part_result) in result.parts() {
+            let Some((status, details)) = check(part_result, answers.get(&(result.day, part)))
+            else {
+                continue;
+            };
+            match status {
+                Status::Pass => passed += 1,
+                Status::Fail => failed += 1,
+                Status::None | Status::NotApplicable => {}
+            }
+
+            println!(
+                "{:<5} {:<5} {:<8} {}",
+                format!("{:02}", result.day),
+                part,
+                status,
+                details
+            );
+        }
+    });
+
+    println!("\n{} passed, {} failed", passed, failed);
+    failed == 0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// Solved, but there is no expected answer to compare with.
+    None,
+    NotApplicable,
+}
+
+impl Status {
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::None => "NONE",
+            Status::NotApplicable => "N/A",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pads like a string, so the status lines up in tables.
+        f.pad(self.label())
+    }
+}
+
+/// Compares a part's result with its expected answer, returning the status and
+/// the details shown next to it, or `None` if the part did not run.
+pub fn check(result: &PartResult, expected: Option<&Answer>) -> Option<(Status, String)> {
+    Some(match (&result.outcome, expected) {
+        (Outcome::Skipped, _) => return None,
+        (Outcome::Solved(Answer::NotApplicable), _) => (
+            Status::NotApplicable,
+            "the puzzle has no such part".to_string(),
+        ),
+        (Outcome::Solved(answer), Some(expected)) if answer == expected => {
+            (Status::Pass, answer.to_string())
+        }
+        (Outcome::Solved(answer), Some(expected)) => (
+            Status::Fail,
+            format!("expected {}, got {}", expected, answer),
+        ),
+        (Outcome::Solved(answer), None) => {
+            (Status::None, format!("no expected answer, got {}", answer))
+        }
+        (Outcome::Failed(message), _) => (Status::Fail, format!("error: {}", message)),
+        (Outcome::Panicked(message), _) => (Status::Fail, format!("panicked: {}", message)),
+        (Outcome::TimedOut, _) => {
+            let time = timing::format_duration(result.time);
+            (Status::Fail, format!("timed out after {}", time))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> io::Result<Answers> {
+        parse_answers(Path::new("answers/2024.txt"), contents)
+    }
+
+    #[test]
+    fn parses_answers_file() {
+        let answers = parse(
+            "\
+# day part answer
+
+1 1 11
+1 2   31
+17 1 4,6,3,5,6,3,5,2,1,0
+23 2 co de ka ta
+",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 4);
+        assert_eq!(answers[&(1, 1)], Answer::Integer(11));
+        assert_eq!(answers[&(1, 2)], Answer::Integer(31));
+        assert_eq!(
+            answers[&(17, 1)],
+            Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string())
+        );
+        assert_eq!(answers[&(23, 2)], Answer::Text("co de ka ta".to_string()));
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_malformed_lines() {
+        let error = |contents| parse(contents).unwrap_err().to_string();
+        assert_eq!(error("1 1 11\n26 1 5\n"), "answers/2024.txt:2: invalid day");
+        assert_eq!(error("0 1 5\n"), "answers/2024.txt:1: invalid day");
+        assert_eq!(error("x 1 5\n"), "answers/2024.txt:1: invalid day");
+        assert_eq!(error("3 3 5\n"), "answers/2024.txt:1: invalid part");
+        assert_eq!(
+            error("# header\n3 1\n"),
+            "answers/2024.txt:2: expected `day part answer`"
+        );
+    }
+}
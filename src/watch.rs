@@ -0,0 +1,173 @@
+//! Watch mode: re-running one day whenever its source or its input changes.
+
+use crate::{cli::Options, memory};
+use aoc::input::{self, InputSource};
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, SystemTime},
+};
+
+/// The crate the binary was built from; its sources are the ones watched.
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// How long to wait after a change before running, so that an editor saving
+/// in several writes is only picked up once.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// Runs the selected day, then again whenever `src/yYYYY/dayNN.rs` or the
+/// day's input is modified. Each run is a fresh `cargo run`, so that edits to
+/// the source are compiled in and compile errors are shown. Only returns if
+/// cargo cannot be started.
+pub fn run(options: &Options) -> Result<(), String> {
+    let selection = options.days[0];
+    let source = Path::new(CRATE_DIR)
+        .join("src")
+        .join(format!("y{}", selection.year))
+        .join(format!("day{:02}.rs", selection.day));
+    let files: Vec<PathBuf> = [Some(source), input::path(selection.year, selection.day)]
+        .into_iter()
+        .flatten()
+        .collect();
+    let cwd = env::current_dir().unwrap_or_default();
+    let names: Vec<String> = files
+        .iter()
+        .map(|file| {
+            file.strip_prefix(&cwd)
+                .unwrap_or(file)
+                .display()
+                .to_string()
+        })
+        .collect();
+
+    let mut stamps = modified(&files);
+    let mut changed = None;
+    loop {
+        clear_screen();
+        if let Some(i) = changed {
+            println!("{} changed\n", names[i]);
+        }
+        run_day(options)?;
+        println!("\nWatching {}, press Ctrl-C to stop", names.join(" and "));
+
+        changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let now = modified(&files);
+            if let Some(i) = (0..files.len()).find(|&i| now[i] != stamps[i]) {
+                thread::sleep(SETTLE);
+                stamps = modified(&files);
+                break Some(i);
+            }
+        };
+    }
+}
+
+/// The modification time of each file, `None` where it does not exist (yet).
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn clear_screen() {
+    if io::stdout().is_terminal() {
+        print!("\x1b[2J\x1b[H");
+        let _ = io::stdout().flush();
+    }
+}
+
+/// Builds and runs the day with the profile and features of this binary.
+fn run_day(options: &Options) -> Result<(), String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = process::Command::new(&cargo);
+    command
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(Path::new(CRATE_DIR).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if memory::AVAILABLE {
+        command.args(["--features", "memory"]);
+    }
+    command.arg("--").args(run_args(options));
+
+    let status = command
+        .status()
+        .map_err(|e| format!("cannot run {}: {}", cargo.to_string_lossy(), e))?;
+    if !status.success() {
+        println!("\n{}", status);
+    }
+    Ok(())
+}
+
+/// The arguments that run the watched day with the same parts, input,
+/// parameters and timeout. Answers are never taken from the cache, as the
+/// point of re-running is to see them solved.
+fn run_args(options: &Options) -> Vec<String> {
+    let selection = options.days[0];
+    let day = match (selection.part1, selection.part2) {
+        (true, false) => format!("{}:1", selection.day),
+        (false, true) => format!("{}:2", selection.day),
+        _ => selection.day.to_string(),
+    };
+    let mut args = vec![
+        day,
+        "--year".to_string(),
+        selection.year.to_string(),
+        "--no-cache".to_string(),
+        "--timeout".to_string(),
+        options
+            .timeout
+            .map_or(0.0, |timeout| timeout.as_secs_f64())
+            .to_string(),
+    ];
+    match &options.input {
+        InputSource::File(path) => args.extend(["--input".to_string(), path.display().to_string()]),
+        InputSource::Dir(dir) => {
+            args.extend(["--input-dir".to_string(), dir.display().to_string()])
+        }
+        InputSource::Default | InputSource::Stdin => {}
+    }
+    if let Some(config) = &options.config {
+        args.extend(["--config".to_string(), config.display().to_string()]);
+    }
+    for param in &options.params {
+        args.extend(["--param".to_string(), param.clone()]);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{parse_args, Command};
+
+    #[test]
+    fn run_args_select_the_watched_day() {
+        let options = parse_args([
+            "watch",
+            "14:1",
+            "--input",
+            "example.txt",
+            "--param",
+            "day14.width=11",
+            "--timeout",
+            "2.5",
+        ])
+        .unwrap();
+        let run = parse_args(run_args(&options)).unwrap();
+
+        assert!(matches!(run.command, Command::Run));
+        assert!(!run.cache);
+        assert_eq!(run.days, options.days);
+        assert_eq!(run.input, options.input);
+        assert_eq!(run.params, options.params);
+        assert_eq!(run.timeout, Some(Duration::from_secs_f64(2.5)));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
+use std::collections::HashMap;
+
+pub struct AocDay1 {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+impl AocDay1 {
+    pub fn new(input: &str) -> Result<AocDay1, AocError> {
+        let input = lines(input);
+        let (mut left, mut right) = Self::parse(&input)?;
+
+        left.sort();
+        right.sort();
+
+        Ok(AocDay1 { left, right })
+    }
+
+    pub fn part1(&self) -> i32 {
+        self.left
+            .iter()
+            .zip(self.right.iter())
+            .fold(0, |acc, (l, r)| acc + (l - r).abs())
+    }
+
+    pub fn part2(&self) -> i32 {
+        let right_map = Self::get_map(&self.right);
+        let mut score = 0;
+
+        for l in self.left.iter() {
+            let times = *right_map.get(&l).unwrap_or(&0);
+            score += l * times;
+        }
+
+        score
+    }
+
+    fn parse(input: &[String]) -> Result<(Vec<i32>, Vec<i32>), AocError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for (row, line) in input.iter().enumerate() {
+            let [l, r] = utils::fields(row, line, line, line.split_whitespace())?;
+            left.push(utils::parse_field(row, line, l)?);
+            right.push(utils::parse_field(row, line, r)?);
+        }
+
+        Ok((left, right))
+    }
+
+    fn get_map(loc: &[i32]) -> HashMap<&i32, i32> {
+        loc.iter().fold(HashMap::new(), |mut map, value| {
+            map.entry(value).and_modify(|x| *x += 1).or_insert(1);
+            map
+        })
+    }
+}
+
+impl AocDay for AocDay1 {
+    type Input = AocDay1;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay1::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let left: Vec<i64> = (0..size.max(1)).map(|_| rng.range(10000..100000)).collect();
+        let mut input = String::new();
+        for &l in &left {
+            // Part 2 scores the left numbers that also appear on the right.
+            let r = if rng.chance(0.3) {
+                *rng.pick(&left)
+            } else {
+                rng.range(10000..100000)
+            };
+            input += &format!("{}   {}\n", l, r);
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay1>(EXAMPLE, 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay1>(EXAMPLE, 31);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
+
+pub struct AocDay2 {
+    reports: Vec<Vec<i32>>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Order {
+    Inc,
+    Dec,
+}
+
+const MIN_DIFF: i32 = 1;
+const MAX_DIFF: i32 = 3;
+
+impl AocDay2 {
+    pub fn new(input: &str) -> Result<AocDay2, AocError> {
+        let input = lines(input);
+        let reports = Self::parse(&input)?;
+        Ok(AocDay2 { reports })
+    }
+
+    pub fn part1(&self) -> i32 {
+        self.reports
+            .iter()
+            .filter(|report| Self::is_safe_report(report))
+            .count() as i32
+    }
+
+    pub fn part2(&self) -> i32 {
+        self.reports
+            .iter()
+            .filter(|report| {
+                if Self::is_safe_report(report) {
+                    return true;
+                }
+
+                report.iter().enumerate().any(|(i, _)| {
+                    let new_report = report
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != i)
+                        .map(|(_, &x)| x)
+                        .collect::<Vec<_>>();
+                    Self::is_safe_report(&new_report)
+                })
+            })
+            .count() as i32
+    }
+
+    fn is_safe_report(report: &[i32]) -> bool {
+        let order = Self::get_order(report, 0, 1);
+        report
+            .iter()
+            .skip(1)
+            .enumerate()
+            .all(|(i, _)| Self::is_safe(report, &order, i, i + 1))
+    }
+
+    fn get_order(report: &[i32], l: usize, r: usize) -> Order {
+        if report[l] > report[r] {
+            Order::Dec
+        } else {
+            Order::Inc
+        }
+    }
+
+    fn is_safe(report: &[i32], order: &Order, left: usize, right: usize) -> bool {
+        let new_order = Self::get_order(report, left, right);
+        if new_order != *order {
+            return false;
+        }
+        let diff = (report[left] - report[right]).abs();
+        (MIN_DIFF..=MAX_DIFF).contains(&diff)
+    }
+
+    fn parse(input: &[String]) -> Result<Vec<Vec<i32>>, AocError> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(row, line)| utils::parse_fields(row, line, line.split_whitespace()))
+            .collect()
+    }
+}
+
+impl AocDay for AocDay2 {
+    type Input = AocDay2;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay2::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let len = rng.range(5..9) as usize;
+            let step = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.range(25..75);
+            let mut levels = Vec::new();
+            for _ in 0..len {
+                levels.push(level);
+                level += step * rng.range(1..4);
+            }
+            // Break half the reports with a bad level, which part 2 may drop.
+            if rng.chance(0.5) {
+                levels[rng.below(len)] = rng.range(1..100);
+            }
+            let levels: Vec<String> = levels.iter().map(ToString::to_string).collect();
+            input += &(levels.join(" ") + "\n");
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay2>(EXAMPLE, 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay2>(EXAMPLE, 4);
+    }
+}
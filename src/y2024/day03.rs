@@ -1,4 +1,4 @@
-use crate::{utils::read_lines, AocDay};
+use crate::{random::Rng, utils::lines, Answer, AocDay, AocError};
 
 const MUL_PATTERN: [char; 3] = ['m', 'u', 'l'];
 const DO_PATTERN: [char; 4] = ['d', 'o', '(', ')'];
@@ -17,12 +17,12 @@ pub struct AocDay3 {
 }
 
 impl AocDay3 {
-    pub fn new() -> AocDay3 {
-        let input = read_lines("input/day3.txt");
-        AocDay3 {
+    pub fn new(input: &str) -> Result<AocDay3, AocError> {
+        let input = lines(input);
+        Ok(AocDay3 {
             input,
             instruction: Instruction::Do,
-        }
+        })
     }
 
     pub fn part1(&self) -> i32 {
@@ -44,10 +44,8 @@ impl AocDay3 {
             let chars = line.chars().collect::<Vec<_>>();
             let mut sum = 0;
             for (j, c) in chars.iter().enumerate() {
-                if *c == 'm' {
-                    if _self.instruction == Instruction::Do {
-                        sum += Self::eval_mul(&chars, j);
-                    }
+                if *c == 'm' && _self.instruction == Instruction::Do {
+                    sum += Self::eval_mul(&chars, j);
                 }
                 if *c == 'd' {
                     if let Some(instruction) = Self::parse_instruction(&chars, j) {
@@ -110,11 +108,75 @@ impl AocDay3 {
 }
 
 impl AocDay for AocDay3 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay3;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay3::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const NOISE: [&str; 14] = [
+            "mul",
+            "mul(",
+            "do",
+            "don't",
+            "(",
+            ")",
+            ",",
+            "[",
+            "]",
+            "%",
+            "@",
+            "what()",
+            "mul[3,7]",
+            "mul ( 2 , 4 )",
+        ];
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..rng.range(20..60) {
+                match rng.below(6) {
+                    0 | 1 => {
+                        input += &format!("mul({},{})", rng.range(1..1000), rng.range(1..1000))
+                    }
+                    2 if rng.chance(0.5) => input += "do()",
+                    2 => input += "don't()",
+                    _ => input += *rng.pick(&NOISE),
+                }
+            }
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE_1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE_2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay3>(EXAMPLE_1, 161);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay3>(EXAMPLE_2, 48);
     }
 }
@@ -1,4 +1,8 @@
-use crate::{utils::read_lines, AocDay};
+use crate::{
+    random::{self, Rng},
+    utils::matrix,
+    Answer, AocDay, AocError,
+};
 
 const SEARCH_STRING: [char; 4] = ['X', 'M', 'A', 'S'];
 
@@ -8,10 +12,10 @@ pub struct AocDay4 {
 }
 
 impl AocDay4 {
-    pub fn new() -> AocDay4 {
-        let input = read_lines("input/day4.txt");
-        let matrix = Self::create_matrix(input);
-        AocDay4 { matrix }
+    pub fn new(input: &str) -> Result<AocDay4, AocError> {
+        Ok(AocDay4 {
+            matrix: matrix(input)?,
+        })
     }
 
     pub fn part1(&self) -> i32 {
@@ -34,7 +38,7 @@ impl AocDay4 {
                 if self.matrix[r][c] == SEARCH_STRING[0] {
                     for (dr, dc) in directions.iter() {
                         if Self::search_in_direction(
-                            &mut _self.matrix,
+                            &_self.matrix,
                             r as isize,
                             c as isize,
                             *dr,
@@ -80,17 +84,8 @@ impl AocDay4 {
         words
     }
 
-    fn create_matrix(input: Vec<String>) -> Vec<Vec<char>> {
-        let mut matrix = vec![];
-        for line in input {
-            let row = line.chars().collect::<Vec<_>>();
-            matrix.push(row);
-        }
-        matrix
-    }
-
     fn search_in_direction(
-        matrix: &Vec<Vec<char>>,
+        matrix: &[Vec<char>],
         r: isize,
         c: isize,
         dr: isize,
@@ -142,11 +137,54 @@ impl AocDay4 {
 }
 
 impl AocDay for AocDay4 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay4;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay4::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(4);
+        let grid: Vec<Vec<char>> = (0..side)
+            .map(|_| (0..side).map(|_| *rng.pick(&SEARCH_STRING)).collect())
+            .collect();
+        Some(random::grid_text(&grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay4>(EXAMPLE, 18);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay4>(EXAMPLE, 9);
     }
 }
@@ -1,4 +1,8 @@
-use crate::{utils::read_lines, AocDay};
+use crate::{
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
 use std::collections::{HashMap, HashSet};
 
 trait RuleMapTrait {
@@ -43,12 +47,12 @@ pub struct AocDay5 {
 }
 
 impl AocDay5 {
-    pub fn new() -> AocDay5 {
-        let input = read_lines("input/day5.txt");
-        let rules = Self::get_rules(&input);
-        let pages = Self::get_pages(&input);
+    pub fn new(input: &str) -> Result<AocDay5, AocError> {
+        let input = lines(input);
+        let rules = Self::get_rules(&input)?;
+        let pages = Self::get_pages(&input)?;
 
-        AocDay5 { rules, pages }
+        Ok(AocDay5 { rules, pages })
     }
 
     pub fn part1(&self) -> i32 {
@@ -88,37 +92,37 @@ impl AocDay5 {
         total_sum
     }
 
-    fn get_rules(lines: &Vec<String>) -> HashMap<i32, Rule> {
+    fn get_rules(lines: &[String]) -> Result<HashMap<i32, Rule>, AocError> {
         let mut rules_map: HashMap<i32, Rule> = HashMap::new();
-        let rules = lines
-            .iter()
-            .take_while(|x| !x.is_empty())
-            .collect::<Vec<_>>();
+        let rules = lines.iter().take_while(|x| !x.is_empty());
 
-        for rule in rules {
-            let mut parts = rule.split("|").map(str::parse::<i32>);
+        for (row, rule) in rules.enumerate() {
+            let [key, val] = utils::fields(row, rule, rule, rule.split("|"))?;
             let (key, val) = (
-                parts.next().unwrap().unwrap(),
-                parts.next().unwrap().unwrap(),
+                utils::parse_field(row, rule, key)?,
+                utils::parse_field(row, rule, val)?,
             );
 
             rules_map.insert_rule(key, val);
         }
 
-        rules_map
+        Ok(rules_map)
     }
 
-    fn get_pages(lines: &Vec<String>) -> Vec<Vec<i32>> {
+    fn get_pages(lines: &[String]) -> Result<Vec<Vec<i32>>, AocError> {
+        let Some(separator) = lines.iter().position(|x| x.is_empty()) else {
+            return Err(AocError::at_row(
+                lines.len(),
+                "expected a blank line between the rules and the updates",
+            ));
+        };
+
         lines
             .iter()
-            .skip_while(|x| !x.is_empty())
-            .skip(1)
-            .map(|line| {
-                line.split(",")
-                    .map(|x| x.parse::<i32>().unwrap())
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>()
+            .enumerate()
+            .skip(separator + 1)
+            .map(|(row, line)| utils::parse_fields(row, line, line.split(",")))
+            .collect()
     }
 
     fn categorize_pages(&self) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
@@ -136,7 +140,7 @@ impl AocDay5 {
         (valid_pages, invalid_pages)
     }
 
-    fn is_page_valid(&self, page: &Vec<i32>) -> bool {
+    fn is_page_valid(&self, page: &[i32]) -> bool {
         for (i, number) in page.iter().enumerate() {
             if let Some(rule) = self.rules.get(number) {
                 for before in page.iter().take(i) {
@@ -156,11 +160,95 @@ impl AocDay5 {
 }
 
 impl AocDay for AocDay5 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay5;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay5::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    /// Orders every pair of pages, so each update has exactly one correct
+    /// order.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut pages: Vec<i64> = (10..100).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(size.clamp(5, pages.len()));
+
+        let mut rules = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in &pages[i + 1..] {
+                rules.push(format!("{}|{}\n", before, after));
+            }
+        }
+        rng.shuffle(&mut rules);
+        let mut input = rules.concat() + "\n";
+
+        // Updates have an odd number of pages, so they have a middle one.
+        let max = pages.len().min(23);
+        for _ in 0..size.max(1) {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(2 * rng.below((max - 1) / 2) + 3);
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|p| p == page));
+            }
+            let update: Vec<String> = update.iter().map(ToString::to_string).collect();
+            input += &(update.join(",") + "\n");
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay5>(EXAMPLE, 143);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay5>(EXAMPLE, 123);
     }
 }
@@ -0,0 +1,260 @@
+use crate::{
+    cancel,
+    random::{self, Rng},
+    utils, Answer, AocDay, AocError,
+};
+use std::collections::HashSet;
+
+/// How many guard steps run between cancellation checks.
+const CHECK_INTERVAL: usize = 4096;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn next_coord(&self, x: i32, y: i32) -> (i32, i32) {
+        match self {
+            Direction::Up => (x, y - 1),
+            Direction::Down => (x, y + 1),
+            Direction::Left => (x - 1, y),
+            Direction::Right => (x + 1, y),
+        }
+    }
+
+    fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+}
+
+pub struct AocDay6 {
+    map: Vec<Vec<char>>,
+}
+
+impl AocDay6 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let map = utils::matrix(input)?;
+        if !map.iter().flatten().any(|&c| c == '^') {
+            return Err(AocError::new("expected a guard `^` on the map"));
+        }
+        Ok(AocDay6 { map })
+    }
+
+    /// Counts the positions the guard visits before leaving the map. Fails
+    /// if the guard comes back to a position facing the same way, as it then
+    /// walks in a loop and never leaves.
+    pub fn part1(&self) -> Result<i32, AocError> {
+        let mut map = self.map.clone();
+        let (mut x, mut y) = self.get_starting_point();
+        let mut direction = Direction::Up;
+        let mut seen = HashSet::new();
+
+        loop {
+            if !seen.insert((x, y, direction)) {
+                return Err(AocError::new(format!(
+                    "the guard walks in a loop from {},{} and never leaves the map",
+                    x, y
+                )));
+            }
+            let (next_x, next_y) = direction.next_coord(x, y);
+
+            if Self::is_edge(&map, next_x, next_y) {
+                map[y as usize][x as usize] = 'X';
+                break;
+            }
+
+            if Self::is_obstruction(&map, next_x, next_y) {
+                direction = direction.turn_right();
+            } else {
+                map[y as usize][x as usize] = 'X';
+                x = next_x;
+                y = next_y;
+            }
+        }
+
+        Ok(map.iter().flatten().filter(|&c| *c == 'X').count() as i32)
+    }
+
+    pub fn part2(&self) -> Result<i32, AocError> {
+        let mut map = self.map.clone();
+        let (start_x, start_y) = self.get_starting_point();
+
+        let mut cycles = 0;
+
+        for r in 0..map.len() {
+            for c in 0..map[0].len() {
+                if map[r][c] == '.' {
+                    map[r][c] = '#';
+
+                    if Self::detect_cycle(&map, start_x, start_y)? {
+                        cycles += 1;
+                    }
+
+                    map[r][c] = '.';
+                }
+            }
+        }
+
+        Ok(cycles)
+    }
+
+    fn get_starting_point(&self) -> (i32, i32) {
+        for y in 0..self.map.len() {
+            for x in 0..self.map[0].len() {
+                if self.map[y][x] == '^' {
+                    return (x as i32, y as i32);
+                }
+            }
+        }
+        panic!("No starting point found");
+    }
+
+    fn is_edge(map: &[Vec<char>], x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || y >= map.len() as i32 || x >= map[0].len() as i32 {
+            return true;
+        }
+        false
+    }
+
+    fn is_obstruction(map: &[Vec<char>], x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || y >= map.len() as i32 || x >= map[0].len() as i32 {
+            return true;
+        }
+        map[y as usize][x as usize] == '#'
+    }
+
+    /// Whether the guard comes back to a position facing the same way,
+    /// counting turns on the spot, before leaving the map.
+    fn detect_cycle(map: &[Vec<char>], x: i32, y: i32) -> Result<bool, AocError> {
+        let (mut x, mut y) = (x, y);
+        let mut direction = Direction::Up;
+        let mut seen = HashSet::new();
+
+        while seen.insert((x, y, direction)) {
+            if seen.len() % CHECK_INTERVAL == 0 {
+                cancel::check()?;
+            }
+
+            let (next_x, next_y) = direction.next_coord(x, y);
+
+            if Self::is_edge(map, next_x, next_y) {
+                return Ok(false);
+            }
+
+            if Self::is_obstruction(map, next_x, next_y) {
+                direction = direction.turn_right();
+            } else {
+                x = next_x;
+                y = next_y;
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Whether the guard starting at `row`, `col` walks off the map.
+fn leaves_map(map: &[Vec<char>], mut row: usize, mut col: usize) -> bool {
+    let mut direction = Direction::Up;
+    let mut seen = HashSet::new();
+
+    while seen.insert((row, col, direction)) {
+        let (next_col, next_row) = direction.next_coord(col as i32, row as i32);
+        if !utils::in_bounds(map, next_row as isize, next_col as isize) {
+            return true;
+        }
+        if map[next_row as usize][next_col as usize] == '#' {
+            direction = direction.turn_right();
+        } else {
+            (row, col) = (next_row as usize, next_col as usize);
+        }
+    }
+    false
+}
+
+impl AocDay for AocDay6 {
+    type Input = AocDay6;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay6::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    /// Only keeps maps the guard walks off, as in the puzzle's inputs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(3);
+        loop {
+            let mut map = vec![vec!['.'; side]; side];
+            for cell in map.iter_mut().flatten() {
+                if rng.chance(0.1) {
+                    *cell = '#';
+                }
+            }
+            let (row, col) = (rng.below(side), rng.below(side));
+            map[row][col] = '^';
+            if leaves_map(&map, row, col) {
+                return Some(random::grid_text(&map));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay6>(EXAMPLE, 41);
+    }
+
+    #[test]
+    fn part2_counts_a_walled_in_guard_as_a_loop() {
+        // The guard only turns, so every new obstruction keeps it looping.
+        check_part2::<AocDay6>(".#.\n#^#\n.#.\n", 4);
+    }
+
+    #[test]
+    fn part1_reports_guard_walking_in_a_loop() {
+        let input = AocDay6::new(".#...\n....#\n#^...\n...#.\n").unwrap();
+        assert_eq!(
+            input.part1().unwrap_err().to_string(),
+            "the guard walks in a loop from 1,2 and never leaves the map"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay6>(EXAMPLE, 6);
+    }
+}
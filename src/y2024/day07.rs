@@ -0,0 +1,179 @@
+use crate::{
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Op {
+    /// `None` if the result does not fit in an `i64`, in which case it can
+    /// match no target, or when concatenating a negative number.
+    fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Op::Add => a.checked_add(b),
+            Op::Multiply => a.checked_mul(b),
+            Op::Concat if b < 0 => None,
+            Op::Concat => {
+                let digits = b.checked_ilog10().unwrap_or(0) + 1;
+                10i64
+                    .checked_pow(digits)
+                    .and_then(|shift| a.checked_mul(shift))
+                    .and_then(|shifted| shifted.checked_add(b))
+            }
+        }
+    }
+}
+
+pub struct AocDay7 {
+    equations: Vec<(i64, Vec<i64>)>,
+}
+
+impl AocDay7 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let input = lines(input);
+        let mut equations = Vec::new();
+
+        for (row, line) in input.iter().enumerate() {
+            let [target, nums] = utils::fields(row, line, line, line.split(":"))?;
+            let target = utils::parse_field(row, line, target)?;
+            let nums: Vec<i64> = utils::parse_fields(row, line, nums.split_whitespace())?;
+            if nums.is_empty() {
+                return Err(AocError::at_row(row, "expected numbers after the `:`"));
+            }
+
+            equations.push((target, nums));
+        }
+
+        Ok(AocDay7 { equations })
+    }
+
+    pub fn part1(&self) -> i64 {
+        let ops = vec![Op::Add, Op::Multiply];
+        self.calc(&ops)
+    }
+
+    pub fn part2(&self) -> i64 {
+        let ops = vec![Op::Add, Op::Multiply, Op::Concat];
+        self.calc(&ops)
+    }
+
+    fn calc(&self, ops: &[Op]) -> i64 {
+        let mut sum = 0;
+
+        for (target, nums) in &self.equations {
+            let mut combos = Vec::new();
+            Self::gen_ops(nums.len() - 1, ops, &mut combos, Vec::new());
+
+            for operations in combos {
+                let total = operations
+                    .iter()
+                    .zip(&nums[1..])
+                    .try_fold(nums[0], |total, (op, &num)| op.apply(total, num));
+
+                if total == Some(*target) {
+                    sum += target;
+                    break;
+                }
+            }
+        }
+
+        sum
+    }
+
+    fn gen_ops(n: usize, ops: &[Op], combos: &mut Vec<Vec<Op>>, current: Vec<Op>) {
+        if n == 0 {
+            combos.push(current.clone());
+            return;
+        }
+
+        for op in ops {
+            let mut next = current.clone();
+            next.push(op.clone());
+            Self::gen_ops(n - 1, ops, combos, next);
+        }
+    }
+}
+
+impl AocDay for AocDay7 {
+    type Input = AocDay7;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay7::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let count = rng.range(2..7);
+            let nums: Vec<i64> = (0..count).map(|_| rng.range(1..100)).collect();
+            // Half the equations can be made true with the three operators.
+            let target = if rng.chance(0.5) {
+                nums[1..]
+                    .iter()
+                    .fold(nums[0], |acc, &n| match rng.below(3) {
+                        0 => acc + n,
+                        1 => acc * n,
+                        _ => format!("{}{}", acc, n).parse().unwrap(),
+                    })
+            } else {
+                rng.range(1..1_000_000)
+            };
+            let nums: Vec<String> = nums.iter().map(ToString::to_string).collect();
+            input += &format!("{}: {}\n", target, nums.join(" "));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay7>(EXAMPLE, 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay7>(EXAMPLE, 11387);
+    }
+
+    #[test]
+    fn overflowing_combinations_do_not_match() {
+        // Concatenating gives 22 digits, and multiplying about 10^22.
+        let input = AocDay7::new("1: 99999999999 99999999999\n").unwrap();
+        assert_eq!(input.part1(), 0);
+        assert_eq!(input.part2(), 0);
+
+        let input = AocDay7::new("1234567: 1234 567\n").unwrap();
+        assert_eq!(input.part2(), 1234567);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{utils::read_matrix, AocDay};
+use crate::{
+    random::{self, Rng},
+    utils::matrix,
+    Answer, AocDay, AocError,
+};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -12,13 +16,13 @@ pub struct AocDay8 {
 }
 
 impl AocDay8 {
-    pub fn new() -> Self {
-        let matrix = read_matrix("input/day8.txt");
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let matrix = matrix(input)?;
         let antennas = Self::get_antennas(&matrix);
-        AocDay8 {
+        Ok(AocDay8 {
             map: matrix,
             antennas,
-        }
+        })
     }
 
     pub fn part1(&self) -> usize {
@@ -118,11 +122,61 @@ impl AocDay8 {
 }
 
 impl AocDay for AocDay8 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay8;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay8::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(2);
+        let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+        // Few frequencies for the map's size, so that antennas pair up.
+        let frequencies = &frequencies[..(side / 4).clamp(1, frequencies.len())];
+
+        let mut map = vec![vec!['.'; side]; side];
+        for _ in 0..side {
+            map[rng.below(side)][rng.below(side)] = *rng.pick(frequencies);
+        }
+        Some(random::grid_text(&map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay8>(EXAMPLE, 14);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay8>(EXAMPLE, 34);
     }
 }
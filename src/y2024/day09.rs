@@ -1,28 +1,33 @@
-use crate::{utils::read_lines, AocDay};
-use std::iter::repeat;
+use crate::{random::Rng, utils::lines, Answer, AocDay, AocError};
 
 pub struct AocDay9 {
     disk_map: Vec<i32>,
 }
 
 impl AocDay9 {
-    pub fn new() -> AocDay9 {
-        let input = read_lines("input/day9.txt");
+    pub fn new(input: &str) -> Result<AocDay9, AocError> {
+        let input = lines(input);
+        let Some(line) = input.first() else {
+            return Err(AocError::new("expected a disk map, found an empty input"));
+        };
         let mut disk_map = Vec::new();
         let mut id = 0;
 
-        for (i, c) in input[0].chars().enumerate() {
-            let size = c.to_digit(10).unwrap() as usize;
+        for (i, c) in line.chars().enumerate() {
+            let size = c
+                .to_digit(10)
+                .ok_or_else(|| AocError::at(0, i, format!("expected a digit, found `{}`", c)))?
+                as usize;
             let free_space = i % 2 != 0;
 
-            disk_map.extend(repeat(if free_space { -1 } else { id }).take(size));
+            disk_map.extend(std::iter::repeat_n(if free_space { -1 } else { id }, size));
 
             if !free_space {
                 id += 1;
             }
         }
 
-        AocDay9 { disk_map }
+        Ok(AocDay9 { disk_map })
     }
 
     pub fn part1(&self) -> i64 {
@@ -106,11 +111,49 @@ impl AocDay9 {
 }
 
 impl AocDay for AocDay9 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay9;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay9::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    /// A disk map of `size` files, each followed by free space but the last.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut map = String::new();
+        for file in 0..size.max(1) {
+            if file > 0 {
+                map.push(char::from(b'0' + rng.below(10) as u8));
+            }
+            map.push(char::from(b'1' + rng.below(9) as u8));
+        }
+        Some(map + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay9>(EXAMPLE, 1928);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay9>(EXAMPLE, 2858);
     }
 }
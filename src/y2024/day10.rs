@@ -1,4 +1,7 @@
-use crate::{utils, AocDay};
+use crate::{
+    random::{self, Rng},
+    utils, Answer, AocDay, AocError,
+};
 use std::collections::HashSet;
 
 pub struct AocDay10 {
@@ -8,10 +11,10 @@ pub struct AocDay10 {
 impl AocDay10 {
     const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
 
-    pub fn new() -> Self {
-        AocDay10 {
-            map: utils::read_num_matrix("input/day10.txt"),
-        }
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Ok(AocDay10 {
+            map: utils::num_matrix(input)?,
+        })
     }
 
     pub fn part1(&self) -> usize {
@@ -76,11 +79,56 @@ impl AocDay10 {
 }
 
 impl AocDay for AocDay10 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay10;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay10::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(1);
+        let map: Vec<Vec<char>> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| char::from(b'0' + rng.below(10) as u8))
+                    .collect()
+            })
+            .collect();
+        Some(random::grid_text(&map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay10>(EXAMPLE, 36);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay10>(EXAMPLE, 81);
     }
 }
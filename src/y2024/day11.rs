@@ -0,0 +1,146 @@
+use crate::{random::Rng, utils, Answer, AocDay, AocError, Params};
+use std::{collections::HashMap, vec};
+
+/// How many times the stones blink in each part.
+const BLINKS: [usize; 2] = [25, 75];
+
+pub struct AocDay11 {
+    nums: Vec<i64>,
+    blinks: [usize; 2],
+}
+
+impl AocDay11 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Self::with_blinks(input, BLINKS)
+    }
+
+    /// Blinks `blinks[0]` times in part 1 and `blinks[1]` times in part 2.
+    pub fn with_blinks(input: &str, blinks: [usize; 2]) -> Result<Self, AocError> {
+        Ok(AocDay11 {
+            nums: Self::get_nums(input)?,
+            blinks,
+        })
+    }
+
+    pub fn part1(&self) -> usize {
+        self.calc(self.blinks[0])
+    }
+
+    pub fn part2(&self) -> usize {
+        self.calc(self.blinks[1])
+    }
+
+    fn calc(&self, blinks: usize) -> usize {
+        let memo = &mut HashMap::new();
+        self.nums
+            .iter()
+            .map(|&n| Self::split(n, 0, blinks, memo))
+            .sum()
+    }
+
+    fn split(
+        n: i64,
+        depth: usize,
+        max_depth: usize,
+        memo: &mut HashMap<(i64, usize), usize>,
+    ) -> usize {
+        if let Some(&result) = memo.get(&(n, depth)) {
+            return result;
+        }
+
+        if depth >= max_depth {
+            return 1;
+        }
+
+        let parts = if n == 0 {
+            vec![1]
+        } else {
+            let num_digits = Self::num_digits(n);
+            match num_digits % 2 {
+                0 => {
+                    let (left, right) = Self::split_digits(n, num_digits);
+                    vec![left, right]
+                }
+                _ => vec![n * 2024],
+            }
+        };
+
+        let sum = parts
+            .iter()
+            .map(|&part| Self::split(part, depth + 1, max_depth, memo))
+            .sum();
+        memo.insert((n, depth), sum);
+
+        sum
+    }
+
+    fn split_digits(n: i64, digits: usize) -> (i64, i64) {
+        let divisor = 10_i64.pow((digits / 2) as u32);
+        (n / divisor, n % divisor)
+    }
+
+    fn num_digits(n: i64) -> usize {
+        if n == 0 {
+            1
+        } else {
+            (n as f64).log10().floor() as usize + 1
+        }
+    }
+
+    fn get_nums(input: &str) -> Result<Vec<i64>, AocError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::new("expected a line of stones, found an empty input"))?;
+        utils::parse_fields(0, line, line.split_whitespace())
+    }
+}
+
+impl AocDay for AocDay11 {
+    type Input = AocDay11;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay11::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        let blinks = [
+            params.get("part1_blinks", BLINKS[0])?,
+            params.get("part2_blinks", BLINKS[1])?,
+        ];
+        AocDay11::with_blinks(input, blinks)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let stones: Vec<String> = (0..size.max(1))
+            .map(|_| match rng.below(4) {
+                0 => rng.range(0..10).to_string(),
+                _ => rng.range(0..1_000_000).to_string(),
+            })
+            .collect();
+        Some(stones.join(" ") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_part1;
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay11>(EXAMPLE, 55312);
+    }
+}
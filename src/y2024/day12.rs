@@ -1,6 +1,7 @@
 use crate::{
-    utils::{in_bounds, read_matrix},
-    AocDay,
+    random::{self, Rng},
+    utils::{in_bounds, matrix},
+    Answer, AocDay, AocError,
 };
 use std::collections::{HashMap, HashSet};
 
@@ -11,10 +12,10 @@ pub struct AocDay12 {
 impl AocDay12 {
     const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
 
-    pub fn new() -> Self {
-        AocDay12 {
-            plot: read_matrix("input/day12.txt"),
-        }
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Ok(AocDay12 {
+            plot: matrix(input)?,
+        })
     }
 
     pub fn part1(&self) -> usize {
@@ -28,7 +29,7 @@ impl AocDay12 {
             let sides = walls
                 .iter()
                 .flat_map(|wall| wall.values())
-                .map(Self::count_distinct_walls)
+                .map(|indices| Self::count_distinct_walls(indices))
                 .sum::<usize>();
             visited.len() * sides
         })
@@ -66,7 +67,7 @@ impl AocDay12 {
     }
 
     fn dfs(
-        matrix: &mut Vec<Vec<char>>,
+        matrix: &mut [Vec<char>],
         r: isize,
         c: isize,
         target: char,
@@ -97,7 +98,7 @@ impl AocDay12 {
 
     fn get_walls_from_region(
         region: &HashSet<(isize, isize)>,
-        matrix: &Vec<Vec<char>>,
+        matrix: &[Vec<char>],
         target: char,
     ) -> Vec<HashMap<isize, Vec<isize>>> {
         let mut left_sides = HashMap::new();
@@ -133,8 +134,8 @@ impl AocDay12 {
         vec![left_sides, right_sides, top_sides, bottom_sides]
     }
 
-    fn count_distinct_walls(indices: &Vec<isize>) -> usize {
-        let mut indices = indices.clone();
+    fn count_distinct_walls(indices: &[isize]) -> usize {
+        let mut indices = indices.to_vec();
         indices.sort();
 
         if indices.is_empty() {
@@ -156,11 +157,68 @@ impl AocDay12 {
 }
 
 impl AocDay for AocDay12 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay12;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay12::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    /// Plants copy a neighbour's type more often than not, growing regions of
+    /// every shape.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const TYPES: [char; 6] = ['A', 'B', 'C', 'D', 'E', 'F'];
+        let side = size.max(1);
+        let mut plot = vec![vec!['A'; side]; side];
+        for r in 0..side {
+            for c in 0..side {
+                plot[r][c] = match rng.below(4) {
+                    0 if r > 0 => plot[r - 1][c],
+                    1 if c > 0 => plot[r][c - 1],
+                    _ => *rng.pick(&TYPES),
+                };
+            }
+        }
+        Some(random::grid_text(&plot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE_1: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE_2: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay12>(EXAMPLE_1, 140);
+        check_part1::<AocDay12>(EXAMPLE_2, 772);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay12>(EXAMPLE_1, 80);
+        check_part2::<AocDay12>(EXAMPLE_2, 436);
     }
 }
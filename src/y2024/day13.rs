@@ -0,0 +1,276 @@
+use crate::{
+    cancel,
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+}
+
+/// How much further away the prizes are in part 2.
+const PRIZE_OFFSET: usize = 10000000000000;
+
+/// How many times each button can be pressed in part 1.
+const MAX_PRESSES: usize = 100;
+
+pub struct AocDay13 {
+    points: Vec<(Point, Point, Point)>,
+}
+
+impl AocDay13 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let lines = lines(input);
+        let mut points = Vec::new();
+
+        for i in (0..lines.len()).step_by(4) {
+            let (a, b, c) = Self::read_points(&lines, i)?;
+            points.push((a, b, c));
+        }
+
+        Ok(AocDay13 { points })
+    }
+
+    pub fn part1(&self) -> usize {
+        Self::calc_total_price(&self.points, MAX_PRESSES)
+    }
+
+    pub fn part2(&self) -> usize {
+        let new_points = self
+            .points
+            .iter()
+            .map(|(a, b, c)| (*a, *b, Point::new(c.x + PRIZE_OFFSET, c.y + PRIZE_OFFSET)))
+            .collect::<Vec<_>>();
+
+        Self::calc_total_price(&new_points, usize::MAX)
+    }
+
+    fn calc_total_price(points: &[(Point, Point, Point)], max_presses: usize) -> usize {
+        let mut total_price = 0;
+
+        for (a, b, c) in points {
+            if let Some((a_presses, b_presses)) = Self::solve(a, b, c) {
+                if a_presses <= max_presses && b_presses <= max_presses {
+                    total_price += a_presses * 3 + b_presses;
+                }
+            }
+        }
+
+        total_price
+    }
+
+    /// The presses of A and B that reach `c`, by Cramer's rule. `None` if
+    /// they are not whole, non-negative numbers, or if the buttons move along
+    /// the same line, which the puzzle's inputs never do.
+    fn solve(a: &Point, b: &Point, c: &Point) -> Option<(usize, usize)> {
+        let [ax, ay, bx, by, cx, cy] = [a.x, a.y, b.x, b.y, c.x, c.y].map(|n| n as i128);
+
+        let det = ax * by - ay * bx;
+        if det == 0 {
+            return None;
+        }
+        let (a_det, b_det) = (cx * by - cy * bx, ax * cy - ay * cx);
+        if a_det % det != 0 || b_det % det != 0 {
+            return None;
+        }
+
+        let (a_presses, b_presses) = (a_det / det, b_det / det);
+        if a_presses < 0 || b_presses < 0 {
+            return None;
+        }
+        Some((a_presses as usize, b_presses as usize))
+    }
+
+    /// The total price without floats or assumptions about the buttons: tries
+    /// every number of A presses, up to 100 in part 1 as the puzzle says. In
+    /// part 2 that is only feasible, and needed, when the buttons move along
+    /// the same line; otherwise it solves the equations in exact integers.
+    fn reference_price(&self, part: u8) -> Result<usize, AocError> {
+        let offset = if part == 1 { 0 } else { PRIZE_OFFSET };
+        let mut total = 0;
+
+        for (a, b, prize) in &self.points {
+            let [ax, ay, bx, by] = [a.x, a.y, b.x, b.y].map(|n| n as i128);
+            let (px, py) = ((prize.x + offset) as i128, (prize.y + offset) as i128);
+            let det = ax * by - ay * bx;
+
+            let cheapest = if part == 1 || det == 0 {
+                let max_presses = if part == 1 {
+                    MAX_PRESSES as i128
+                } else {
+                    i128::MAX
+                };
+                let mut cheapest = None;
+                let mut a_presses = 0;
+                while a_presses <= max_presses {
+                    if a_presses % (1 << 20) == 0 {
+                        cancel::check()?;
+                    }
+                    let (rx, ry) = (px - a_presses * ax, py - a_presses * ay);
+                    if rx < 0 || ry < 0 {
+                        break;
+                    }
+                    let b_presses = match (bx, by) {
+                        (0, 0) => 0,
+                        (0, _) => ry / by,
+                        _ => rx / bx,
+                    };
+                    if b_presses * bx == rx && b_presses * by == ry && b_presses <= max_presses {
+                        let price = 3 * a_presses + b_presses;
+                        cheapest = Some(cheapest.map_or(price, |c: i128| c.min(price)));
+                    }
+                    a_presses += 1;
+                }
+                cheapest
+            } else {
+                let (a_det, b_det) = (px * by - py * bx, ax * py - ay * px);
+                let exact = a_det % det == 0 && b_det % det == 0;
+                let (a_presses, b_presses) = (a_det / det, b_det / det);
+                (exact && a_presses >= 0 && b_presses >= 0).then(|| 3 * a_presses + b_presses)
+            };
+            total += cheapest.unwrap_or(0) as usize;
+        }
+
+        Ok(total)
+    }
+
+    fn read_points(lines: &[String], index: usize) -> Result<(Point, Point, Point), AocError> {
+        let a = Self::read_point(lines, index, "Button A: ", "+")?;
+        let b = Self::read_point(lines, index + 1, "Button B: ", "+")?;
+        let c = Self::read_point(lines, index + 2, "Prize: ", "=")?;
+
+        Ok((a, b, c))
+    }
+
+    /// Reads a line such as `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+    fn read_point(
+        lines: &[String],
+        row: usize,
+        label: &str,
+        sign: &str,
+    ) -> Result<Point, AocError> {
+        let line = lines.get(row).ok_or_else(|| {
+            AocError::at_row(
+                row,
+                format!("expected `{}`, found the end of the input", label.trim()),
+            )
+        })?;
+
+        let coords = utils::strip_prefix(row, line, line, label)?;
+        let [x, y] = utils::fields(row, line, coords, coords.split(", "))?;
+        let x = utils::strip_prefix(row, line, x, &format!("X{}", sign))?;
+        let y = utils::strip_prefix(row, line, y, &format!("Y{}", sign))?;
+
+        Ok(Point::new(
+            utils::parse_field(row, line, x)?,
+            utils::parse_field(row, line, y)?,
+        ))
+    }
+}
+
+impl AocDay for AocDay13 {
+    type Input = AocDay13;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay13::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn reference(input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(input.reference_price(part).map(Answer::from))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for machine in 0..size.max(1) {
+            if machine > 0 {
+                input.push('\n');
+            }
+            // The puzzle's buttons never move the claw along the same line.
+            let [ax, ay, bx, by] = loop {
+                let buttons = [(); 4].map(|_| rng.range(10..100));
+                if buttons[0] * buttons[3] != buttons[1] * buttons[2] {
+                    break buttons;
+                }
+            };
+            // Half the prizes can be won.
+            let (px, py) = if rng.chance(0.5) {
+                let (a, b) = (rng.range(1..101), rng.range(1..101));
+                (a * ax + b * bx, a * ay + b * by)
+            } else {
+                (rng.range(1000..20000), rng.range(1000..20000))
+            };
+            input += &format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                ax, ay, bx, by, px, py
+            );
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_reference, parse};
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay13>(EXAMPLE, 480);
+    }
+
+    #[test]
+    fn reference_matches_the_solver() {
+        check_reference::<AocDay13>(EXAMPLE, 1, 480);
+        let input = parse::<AocDay13>(EXAMPLE);
+        check_reference::<AocDay13>(EXAMPLE, 2, input.part2());
+    }
+
+    #[test]
+    fn rejects_negative_presses() {
+        // Reaching the prize takes 437 presses of A and -212 of B.
+        let machine = "\
+Button A: X+47, Y+66
+Button B: X+18, Y+45
+Prize: X=16723, Y=19302
+";
+        check_part1::<AocDay13>(machine, 0);
+        check_reference::<AocDay13>(machine, 1, 0);
+        let input = parse::<AocDay13>(machine);
+        check_reference::<AocDay13>(machine, 2, input.part2());
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    cancel,
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError, Params,
+};
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+/// The example robots from the puzzle text, which move on an 11x7 grid.
+pub const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+#[derive(Debug, Clone)]
+struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn new(x: i32, y: i32, vx: i32, vy: i32) -> Robot {
+        Robot { x, y, vx, vy }
+    }
+}
+
+pub struct AocDay14 {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl AocDay14 {
+    pub fn new(input: &str) -> Result<AocDay14, AocError> {
+        Self::with_size(input, WIDTH, HEIGHT)
+    }
+
+    /// Simulates the robots on a `width` by `height` grid. The puzzle's
+    /// examples use an 11x7 grid instead of 101x103.
+    pub fn with_size(input: &str, width: i32, height: i32) -> Result<AocDay14, AocError> {
+        let input = lines(input);
+        let robots = Self::get_robots(&input, width, height)?;
+        Ok(AocDay14 {
+            robots,
+            width,
+            height,
+        })
+    }
+
+    pub fn part1(&self) -> usize {
+        let mut map = self.init_map();
+        let mut robots = self.robots.clone();
+
+        for _ in 0..100 {
+            Self::teleport(&mut map, &mut robots);
+        }
+
+        Self::calc_quadrants(&map)
+    }
+
+    /// Waits for the robots to draw a Christmas tree. Every robot is back
+    /// where it started after `width * height` seconds, so a tree that has not
+    /// appeared by then never will.
+    pub fn part2(&self) -> Result<usize, AocError> {
+        let mut map = self.init_map();
+        let mut robots = self.robots.clone();
+        let period = self.width as usize * self.height as usize;
+
+        for seconds in 1..=period {
+            cancel::check()?;
+            Self::teleport(&mut map, &mut robots);
+            if Self::is_tree(&map) {
+                return Ok(seconds);
+            }
+        }
+
+        Err(AocError::new(format!(
+            "the robots never draw a tree, their positions repeat every {} seconds",
+            period
+        )))
+    }
+
+    fn init_map(&self) -> Vec<Vec<usize>> {
+        let mut map = vec![vec![0; self.width as usize]; self.height as usize];
+        for robot in self.robots.iter() {
+            map[robot.y as usize][robot.x as usize] += 1;
+        }
+        map
+    }
+
+    fn teleport(map: &mut [Vec<usize>], robots: &mut [Robot]) {
+        let x_len = map[0].len() as i32;
+        let y_len = map.len() as i32;
+
+        for robot in robots.iter_mut() {
+            map[robot.y as usize][robot.x as usize] -= 1;
+
+            let new_x = (robot.x + robot.vx + x_len) % x_len;
+            let new_y = (robot.y + robot.vy + y_len) % y_len;
+
+            robot.x = new_x;
+            robot.y = new_y;
+
+            map[robot.y as usize][robot.x as usize] += 1;
+        }
+    }
+
+    fn calc_quadrants(map: &[Vec<usize>]) -> usize {
+        let mid_x = map.len() / 2;
+        let mid_y = map[0].len() / 2;
+
+        let mut top_left = 0;
+        let mut top_right = 0;
+        let mut bottom_left = 0;
+        let mut bottom_right = 0;
+
+        for r in 0..mid_x {
+            for c in 0..mid_y {
+                top_left += map[r][c];
+            }
+            for c in mid_y + 1..map[0].len() {
+                top_right += map[r][c];
+            }
+        }
+
+        for r in mid_x + 1..map.len() {
+            for c in 0..mid_y {
+                bottom_left += map[r][c];
+            }
+            for c in mid_y + 1..map[0].len() {
+                bottom_right += map[r][c];
+            }
+        }
+
+        top_left * top_right * bottom_left * bottom_right
+    }
+
+    /// Whether some robot is the top of a tree 21 tiles wide and 5 tall,
+    /// which a smaller grid cannot hold.
+    fn is_tree(map: &[Vec<usize>]) -> bool {
+        let width_list = vec![10, 8, 6, 4];
+
+        for r in 4..map.len() {
+            for c in 10..map[0].len().saturating_sub(10) {
+                if map[r][c] == 1 {
+                    let mut found_all_widths = true;
+
+                    for &width in &width_list {
+                        let mut found = false;
+                        for row in r - 4..=r {
+                            let mut all_ones = true;
+                            for idx in c - width..=c + width {
+                                if map[row][idx] == 0 {
+                                    all_ones = false;
+                                    break;
+                                }
+                            }
+                            if all_ones {
+                                found = true;
+                                break;
+                            }
+                        }
+                        if !found {
+                            found_all_widths = false;
+                            break;
+                        }
+                    }
+
+                    if found_all_widths {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn get_robots(input: &[String], width: i32, height: i32) -> Result<Vec<Robot>, AocError> {
+        let mut robots = Vec::new();
+
+        for (row, line) in input.iter().enumerate() {
+            let [p, v] = utils::fields(row, line, line, line.split_whitespace())?;
+            let [x, y] = Self::read_pair(row, line, p, "p=")?;
+            let [vx, vy] = Self::read_pair(row, line, v, "v=")?;
+
+            if !(0..width).contains(&x) || !(0..height).contains(&y) {
+                return Err(AocError::at(
+                    row,
+                    utils::column(line, p),
+                    format!(
+                        "position {},{} is outside the {}x{} grid",
+                        x, y, width, height
+                    ),
+                ));
+            }
+
+            robots.push(Robot::new(x, y, vx, vy));
+        }
+
+        Ok(robots)
+    }
+
+    /// Reads a field such as `p=49,14`.
+    fn read_pair(row: usize, line: &str, field: &str, prefix: &str) -> Result<[i32; 2], AocError> {
+        let pair = utils::strip_prefix(row, line, field, prefix)?;
+        let [a, b] = utils::fields(row, line, pair, pair.split(','))?;
+        Ok([
+            utils::parse_field(row, line, a)?,
+            utils::parse_field(row, line, b)?,
+        ])
+    }
+
+    /// `size` random robots, plus robots that draw a tree at a random second
+    /// if it fits on the grid. Without the tree, part 2 never finishes.
+    fn random_robots(rng: &mut Rng, size: usize, width: i32, height: i32) -> String {
+        let (width, height) = (width as i64, height as i64);
+        let mut positions = Vec::new();
+        if width > 20 && height > 4 {
+            let (x, y) = (rng.range(10..width - 10), rng.range(4..height));
+            for (dy, half_width) in [(4, 0), (3, 4), (2, 6), (1, 8), (0, 10)] {
+                for dx in -half_width..=half_width {
+                    positions.push((x + dx, y - dy));
+                }
+            }
+        }
+        for _ in 0..size.max(1) {
+            positions.push((rng.range(0..width), rng.range(0..height)));
+        }
+
+        let seconds = rng.range(1..width * height + 1);
+        let mut input = String::new();
+        for (x, y) in positions {
+            let (vx, vy) = (rng.range(1 - width..width), rng.range(1 - height..height));
+            input += &format!(
+                "p={},{} v={},{}\n",
+                (x - vx * seconds).rem_euclid(width),
+                (y - vy * seconds).rem_euclid(height),
+                vx,
+                vy
+            );
+        }
+        input
+    }
+}
+
+impl AocDay for AocDay14 {
+    type Input = AocDay14;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay14::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        AocDay14::with_size(
+            input,
+            params.get_at_least("width", WIDTH, 1)?,
+            params.get_at_least("height", HEIGHT, 1)?,
+        )
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(AocDay14::random_robots(rng, size, WIDTH, HEIGHT))
+    }
+
+    fn generate_with(rng: &mut Rng, size: usize, params: &Params) -> Option<String> {
+        // Invalid sizes fail the parse instead.
+        let width = params.get_at_least("width", WIDTH, 1).unwrap_or(WIDTH);
+        let height = params.get_at_least("height", HEIGHT, 1).unwrap_or(HEIGHT);
+        Some(AocDay14::random_robots(rng, size, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        // The example robots move on an 11x7 grid.
+        let input = AocDay14::with_size(EXAMPLE, 11, 7).unwrap();
+        assert_eq!(input.part1(), 12);
+    }
+
+    #[test]
+    fn part2_gives_up_on_grids_without_a_tree() {
+        // Too narrow for the tree, and the example's robots never draw one.
+        let cases = [("p=0,0 v=1,1\np=4,6 v=-2,3\n", 5, 7), (EXAMPLE, 11, 7)];
+        for (robots, width, height) in cases {
+            let input = AocDay14::with_size(robots, width, height).unwrap();
+            assert_eq!(
+                input.part2().unwrap_err().to_string(),
+                format!(
+                    "the robots never draw a tree, their positions repeat every {} seconds",
+                    width * height
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_robot_outside_grid() {
+        assert!(AocDay14::with_size("p=11,0 v=1,1", 11, 7).is_err());
+    }
+
+    #[test]
+    fn reports_the_malformed_pair() {
+        let error = AocDay14::new("p=1,2 v=3").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 9: expected 2 fields, found 1 in `3`"
+        );
+    }
+}
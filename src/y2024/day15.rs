@@ -1,6 +1,7 @@
 use crate::{
-    utils::{in_bounds, read_lines},
-    AocDay,
+    random::{self, Rng},
+    utils::{in_bounds, lines, matrix},
+    Answer, AocDay, AocError,
 };
 use std::{cell::RefCell, rc::Rc};
 
@@ -77,9 +78,9 @@ pub struct AocDay15 {
 }
 
 impl AocDay15 {
-    pub fn new() -> AocDay15 {
-        let (map, directions) = parse_input();
-        AocDay15 { map, directions }
+    pub fn new(input: &str) -> Result<AocDay15, AocError> {
+        let (map, directions) = parse_input(input)?;
+        Ok(AocDay15 { map, directions })
     }
 
     pub fn part1(&self) -> usize {
@@ -87,7 +88,7 @@ impl AocDay15 {
         let mut robot_pos = get_robot_position(&map);
 
         for d in self.directions.iter() {
-            let next_pos = robot_pos.next(&d);
+            let next_pos = robot_pos.next(d);
             if in_bounds(&map, next_pos.r as isize, next_pos.c as isize) {
                 robot_pos = move_robot(&mut map, d, &robot_pos);
             }
@@ -101,42 +102,42 @@ impl AocDay15 {
         let mut robot_pos = get_robot_position(&map);
 
         for direction in self.directions.iter() {
-            robot_pos = move_robot(&mut map, &direction, &robot_pos);
+            robot_pos = move_robot(&mut map, direction, &robot_pos);
         }
 
         sum_coordinates(&map, '[')
     }
 }
 
-fn move_robot(map: &mut Vec<Vec<char>>, direction: &Direction, pos: &Position) -> Position {
+fn move_robot(map: &mut [Vec<char>], direction: &Direction, pos: &Position) -> Position {
     let next = pos.next(direction);
 
     match map[next.r][next.c] {
-        '#' => return pos.clone(),
+        '#' => *pos,
         'O' => {
             if shift(map, direction, next.r as isize, next.c as isize) {
-                return next;
+                next
             } else {
-                return pos.clone();
+                *pos
             }
         }
         '[' | ']' => {
             let moved = shift_extended(map, pos, direction);
             if !moved {
-                return pos.clone();
+                return *pos;
             }
             map[next.r][next.c] = '@';
             map[pos.r][pos.c] = '.';
-            return next;
+            next
         }
         _ => {
             map[pos.r][pos.c] = '.';
             map[next.r][next.c] = '@';
-            return next;
+            next
         }
     }
 }
-fn shift(map: &mut Vec<Vec<char>>, direction: &Direction, r: isize, c: isize) -> bool {
+fn shift(map: &mut [Vec<char>], direction: &Direction, r: isize, c: isize) -> bool {
     let (mut new_r, mut new_c) = (r, c);
     let mut can_shift = false;
 
@@ -207,7 +208,7 @@ fn shift(map: &mut Vec<Vec<char>>, direction: &Direction, r: isize, c: isize) ->
     true
 }
 
-fn shift_extended(map: &mut Vec<Vec<char>>, robot: &Position, direction: &Direction) -> bool {
+fn shift_extended(map: &mut [Vec<char>], robot: &Position, direction: &Direction) -> bool {
     match direction {
         Direction::Up | Direction::Down => {
             let root = build_tree(map, robot, direction);
@@ -215,7 +216,7 @@ fn shift_extended(map: &mut Vec<Vec<char>>, robot: &Position, direction: &Direct
                 let mut old_pos = Vec::new();
                 let mut new_pos = Vec::new();
 
-                move_tree(map, &root, *direction, &mut old_pos, &mut new_pos);
+                move_tree(&root, *direction, &mut old_pos, &mut new_pos);
 
                 for old in old_pos.iter() {
                     map[old.0.r][old.0.c] = '.';
@@ -274,7 +275,7 @@ fn shift_extended(map: &mut Vec<Vec<char>>, robot: &Position, direction: &Direct
     false
 }
 
-fn build_tree(map: &Vec<Vec<char>>, robot: &Position, direction: &Direction) -> Rc<Node> {
+fn build_tree(map: &[Vec<char>], robot: &Position, direction: &Direction) -> Rc<Node> {
     let next_pos = robot.next(direction);
     let (left, right) = get_box_position(map, next_pos.r, next_pos.c);
 
@@ -300,14 +301,14 @@ fn build_tree(map: &Vec<Vec<char>>, robot: &Position, direction: &Direction) ->
     node
 }
 
-fn get_box_position(map: &Vec<Vec<char>>, row: usize, col: usize) -> (Position, Position) {
+fn get_box_position(map: &[Vec<char>], row: usize, col: usize) -> (Position, Position) {
     if map[row][col] == '[' {
         return (Position::new(row, col), Position::new(row, col + 1));
     }
     (Position::new(row, col - 1), Position::new(row, col))
 }
 
-fn check_leaves_movable(map: &Vec<Vec<char>>, node: &Rc<Node>, direction: Direction) -> bool {
+fn check_leaves_movable(map: &[Vec<char>], node: &Rc<Node>, direction: Direction) -> bool {
     if !has_free_space(map, node, &direction) {
         return false;
     }
@@ -321,7 +322,7 @@ fn check_leaves_movable(map: &Vec<Vec<char>>, node: &Rc<Node>, direction: Direct
     true
 }
 
-fn has_free_space(map: &Vec<Vec<char>>, node: &Node, direction: &Direction) -> bool {
+fn has_free_space(map: &[Vec<char>], node: &Node, direction: &Direction) -> bool {
     let (start_r, start_c_left, start_c_right) = match direction {
         Direction::Up => (node.left.0.saturating_sub(1), node.left.1, node.right.1),
         Direction::Down => (node.left.0 + 1, node.left.1, node.right.1),
@@ -332,14 +333,13 @@ fn has_free_space(map: &Vec<Vec<char>>, node: &Node, direction: &Direction) -> b
 }
 
 fn move_tree(
-    map: &mut Vec<Vec<char>>,
     node: &Rc<Node>,
     direction: Direction,
     old_positions: &mut Vec<(Position, Position)>,
     new_positions: &mut Vec<(Position, Position)>,
 ) {
     for child in node.children.borrow().iter() {
-        move_tree(map, child, direction, old_positions, new_positions);
+        move_tree(child, direction, old_positions, new_positions);
     }
 
     let left = Position::new(node.left.0, node.left.1).next(&direction);
@@ -352,7 +352,7 @@ fn move_tree(
     ));
 }
 
-fn is_box(map: &Vec<Vec<char>>, row: usize, col: usize) -> bool {
+fn is_box(map: &[Vec<char>], row: usize, col: usize) -> bool {
     if !in_bounds(map, row as isize, col as isize) {
         return false;
     }
@@ -364,7 +364,7 @@ fn is_box(map: &Vec<Vec<char>>, row: usize, col: usize) -> bool {
     false
 }
 
-fn is_free(map: &Vec<Vec<char>>, row: usize, col: usize) -> bool {
+fn is_free(map: &[Vec<char>], row: usize, col: usize) -> bool {
     if in_bounds(map, row as isize, col as isize) && map[row][col] != '#' {
         return true;
     }
@@ -372,7 +372,7 @@ fn is_free(map: &Vec<Vec<char>>, row: usize, col: usize) -> bool {
     false
 }
 
-fn swap(map: &mut Vec<Vec<char>>, d: Direction, r: isize, c: isize) {
+fn swap(map: &mut [Vec<char>], d: Direction, r: isize, c: isize) {
     let (new_r, new_c) = match d {
         Direction::Left => (r, c - 1),
         Direction::Right => (r, c + 1),
@@ -385,7 +385,7 @@ fn swap(map: &mut Vec<Vec<char>>, d: Direction, r: isize, c: isize) {
     map[new_r as usize][new_c as usize] = temp;
 }
 
-pub fn sum_coordinates(map: &Vec<Vec<char>>, target: char) -> usize {
+pub fn sum_coordinates(map: &[Vec<char>], target: char) -> usize {
     let mut sum = 0;
     for r in 0..map.len() {
         for c in 0..map[r].len() {
@@ -398,7 +398,7 @@ pub fn sum_coordinates(map: &Vec<Vec<char>>, target: char) -> usize {
     sum
 }
 
-pub fn get_robot_position(map: &Vec<Vec<char>>) -> Position {
+pub fn get_robot_position(map: &[Vec<char>]) -> Position {
     for r in 0..map.len() {
         for c in 0..map[r].len() {
             if map[r][c] == '@' {
@@ -410,7 +410,7 @@ pub fn get_robot_position(map: &Vec<Vec<char>>) -> Position {
     panic!("Robot not found");
 }
 
-fn resize_map(map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+fn resize_map(map: &[Vec<char>]) -> Vec<Vec<char>> {
     let mut new_map = vec![vec!['.'; map[0].len() * 2]; map.len()];
 
     for r in 0..map.len() {
@@ -442,42 +442,121 @@ fn resize_map(map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
     new_map
 }
 
-pub fn parse_input() -> (Vec<Vec<char>>, Vec<Direction>) {
-    let input = read_lines("input/day15.txt");
+pub fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Direction>), AocError> {
+    let input = lines(input);
     let empty_line_index = input
         .iter()
         .position(|line| line.is_empty())
-        .expect("Invalid input");
+        .ok_or_else(|| {
+            AocError::at_row(
+                input.len(),
+                "expected a blank line between the warehouse and the moves",
+            )
+        })?;
 
-    let map = input
-        .iter()
-        .take(empty_line_index)
-        .map(|line| line.chars().collect())
-        .collect();
+    let map = matrix(&input[..empty_line_index].join("\n"))?;
 
-    let directions = input
-        .iter()
-        .skip(empty_line_index + 1)
-        .flat_map(|line| line.chars())
-        .map(|c| match c {
-            '^' => Ok(Direction::Up),
-            'v' => Ok(Direction::Down),
-            '<' => Ok(Direction::Left),
-            '>' => Ok(Direction::Right),
-            _ => Err("Invalid direction character"),
-        })
-        .collect::<Result<Vec<_>, _>>()
-        .expect("Invalid directions");
+    if !map.iter().flatten().any(|&c| c == '@') {
+        return Err(AocError::new("the warehouse has no robot `@`"));
+    }
 
-    (map, directions)
+    let mut directions = Vec::new();
+    for (row, line) in input.iter().enumerate().skip(empty_line_index + 1) {
+        for (col, c) in line.chars().enumerate() {
+            directions.push(match c {
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                _ => {
+                    return Err(AocError::at(
+                        row,
+                        col,
+                        format!("expected one of `^v<>`, found `{}`", c),
+                    ))
+                }
+            });
+        }
+    }
+
+    Ok((map, directions))
 }
 
 impl AocDay for AocDay15 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay15;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay15::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(4);
+        let mut map = vec![vec!['#'; side]; side];
+        for row in &mut map[1..side - 1] {
+            for cell in &mut row[1..side - 1] {
+                *cell = match rng.below(20) {
+                    0 | 1 => '#',
+                    2..=6 => 'O',
+                    _ => '.',
+                };
+            }
+        }
+        let (row, col) = (rng.range(1..side as i64 - 1), rng.range(1..side as i64 - 1));
+        map[row as usize][col as usize] = '@';
+
+        let mut input = random::grid_text(&map) + "\n";
+        for _ in 0..side / 2 {
+            let moves: String = (0..70).map(|_| *rng.pick(&['^', 'v', '<', '>'])).collect();
+            input += &(moves + "\n");
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay15>(EXAMPLE, 10092);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay15>(EXAMPLE, 9021);
     }
 }
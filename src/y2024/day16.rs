@@ -1,6 +1,7 @@
 use crate::{
-    utils::{in_bounds, read_matrix},
-    AocDay,
+    random::{self, Rng},
+    utils::{in_bounds, matrix},
+    Answer, AocDay, AocError,
 };
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -23,8 +24,14 @@ impl Direction {
     }
 }
 
+type Point = (isize, isize);
+type State = (isize, isize, Direction);
+type Path = Vec<State>;
+
 pub struct AocDay16 {
     pub matrix: Vec<Vec<char>>,
+    start: (isize, isize),
+    end: (isize, isize),
 }
 
 impl AocDay16 {
@@ -35,22 +42,22 @@ impl AocDay16 {
         Direction::Right,
     ];
 
-    pub fn new() -> AocDay16 {
-        let matrix = read_matrix("input/day16.txt");
-        AocDay16 { matrix }
+    pub fn new(input: &str) -> Result<AocDay16, AocError> {
+        let matrix = matrix(input)?;
+        let (start, end) = Self::find_start_end(&matrix)?;
+
+        Ok(AocDay16 { matrix, start, end })
     }
 
     pub fn part1(&self) -> isize {
-        let (start, end) = Self::find_start_end(&self.matrix);
-        let (_, visited) = Self::find_paths(&self.matrix, start);
-        Self::get_min_price(&visited, end)
+        let (_, visited) = Self::find_paths(&self.matrix, self.start);
+        Self::get_min_price(&visited, self.end)
     }
 
     pub fn part2(&self) -> usize {
         let matrix = self.matrix.clone();
-        let (start, end) = Self::find_start_end(&matrix);
-        let (paths, visited) = Self::find_paths(&matrix, start);
-        let min_price = Self::get_min_price(&visited, end);
+        let (paths, visited) = Self::find_paths(&matrix, self.start);
+        let min_price = Self::get_min_price(&visited, self.end);
 
         let mut unique_positions = HashSet::new();
         for path in paths.iter() {
@@ -78,12 +85,9 @@ impl AocDay16 {
     }
 
     fn find_paths(
-        matrix: &Vec<Vec<char>>,
+        matrix: &[Vec<char>],
         start: (isize, isize),
-    ) -> (
-        Vec<(isize, Vec<(isize, isize, Direction)>)>,
-        HashMap<(isize, isize, Direction), isize>,
-    ) {
+    ) -> (Vec<(isize, Path)>, HashMap<State, isize>) {
         let mut queue = VecDeque::new();
         let mut visited = HashMap::new();
         let mut paths = Vec::new();
@@ -110,7 +114,7 @@ impl AocDay16 {
 
                 if visited
                     .get(&(new_row, new_col, next_dir))
-                    .map_or(true, |&existing_price| new_price <= existing_price)
+                    .is_none_or(|&existing_price| new_price <= existing_price)
                 {
                     let mut new_path = path.clone();
                     new_path.push((new_row, new_col, next_dir));
@@ -124,33 +128,88 @@ impl AocDay16 {
         (paths, visited)
     }
 
-    fn find_start_end(matrix: &Vec<Vec<char>>) -> ((isize, isize), (isize, isize)) {
-        let mut start = (0, 0);
-        let mut end = (0, 0);
+    fn find_start_end(matrix: &[Vec<char>]) -> Result<(Point, Point), AocError> {
+        let mut start = None;
+        let mut end = None;
 
         for (i, row) in matrix.iter().enumerate() {
             for (j, c) in row.iter().enumerate() {
                 if *c == 'S' {
-                    start = (i, j);
+                    start = Some((i as isize, j as isize));
                 } else if *c == 'E' {
-                    end = (i, j);
+                    end = Some((i as isize, j as isize));
                 }
             }
         }
 
-        (
-            (start.0 as isize, start.1 as isize),
-            (end.0 as isize, end.1 as isize),
-        )
+        match (start, end) {
+            (Some(start), Some(end)) => Ok((start, end)),
+            (None, _) => Err(AocError::new("expected a start `S` on the map")),
+            (_, None) => Err(AocError::new("expected an end `E` on the map")),
+        }
     }
 }
 
 impl AocDay for AocDay16 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay16;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay16::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    /// A maze with some walls knocked out, so that several paths may tie for
+    /// the lowest score, from the bottom left to the top right.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(5) | 1;
+        let mut maze = random::maze(rng, side, side);
+        for _ in 0..side * side / 40 {
+            let (r, c) = (rng.range(1..side as i64 - 1), rng.range(1..side as i64 - 1));
+            maze[r as usize][c as usize] = '.';
+        }
+        maze[side - 2][1] = 'S';
+        maze[1][side - 2] = 'E';
+        Some(random::grid_text(&maze))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay16>(EXAMPLE, 7036);
     }
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay16>(EXAMPLE, 45);
     }
 }
@@ -0,0 +1,596 @@
+use crate::{cancel, random::Rng, utils, Answer, AocDay, AocError};
+
+/// How many instructions run between cancellation checks.
+const CHECK_INTERVAL: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpCode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl OpCode {
+    fn from(opcode: isize) -> Option<Self> {
+        match opcode {
+            0 => Some(OpCode::Adv),
+            1 => Some(OpCode::Bxl),
+            2 => Some(OpCode::Bst),
+            3 => Some(OpCode::Jnz),
+            4 => Some(OpCode::Bxc),
+            5 => Some(OpCode::Out),
+            6 => Some(OpCode::Bdv),
+            7 => Some(OpCode::Cdv),
+            _ => None,
+        }
+    }
+
+    /// Whether the operand is a combo operand rather than a literal one.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            OpCode::Adv | OpCode::Bst | OpCode::Out | OpCode::Bdv | OpCode::Cdv
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Register {
+    value: isize,
+}
+
+#[derive(Debug)]
+struct Computer {
+    registers: [Register; 3],
+    instructions: Vec<isize>,
+    out: Vec<isize>,
+    pc: usize,
+}
+
+impl Computer {
+    fn new(a: isize, b: isize, c: isize, instructions: Vec<isize>) -> Computer {
+        Computer {
+            registers: [
+                Register { value: a },
+                Register { value: b },
+                Register { value: c },
+            ],
+            instructions,
+            out: Vec::new(),
+            pc: 0,
+        }
+    }
+
+    /// Runs the program until it halts, which a program that jumps back
+    /// forever never does. Reading an opcode or its operand past the end of
+    /// the program halts it.
+    fn run(&mut self) -> Result<(), AocError> {
+        let mut steps = 0;
+        while self.pc + 1 < self.instructions.len() {
+            steps += 1;
+            if steps % CHECK_INTERVAL == 0 {
+                cancel::check()?;
+            }
+
+            let opcode = OpCode::from(self.instructions[self.pc]).ok_or_else(|| {
+                AocError::new(format!("invalid opcode {}", self.instructions[self.pc]))
+            })?;
+            let operand = self.instructions[self.pc + 1];
+            match opcode {
+                OpCode::Adv | OpCode::Bdv | OpCode::Cdv => {
+                    self.division(opcode, self.combo_operand(operand)?);
+                }
+                OpCode::Bxl => {
+                    self.registers[1].value ^= operand;
+                }
+                OpCode::Bst | OpCode::Out => {
+                    let result = self.combo_operand(operand)? % 8;
+                    if opcode == OpCode::Bst {
+                        self.registers[1].value = result;
+                    } else {
+                        self.out.push(result);
+                    }
+                }
+                OpCode::Jnz => {
+                    if self.registers[0].value != 0 {
+                        self.pc = operand as usize;
+                        continue;
+                    }
+                }
+                OpCode::Bxc => {
+                    self.registers[1].value ^= self.registers[2].value;
+                }
+            }
+            self.pc += 2;
+        }
+        Ok(())
+    }
+
+    fn combo_operand(&self, operand: isize) -> Result<isize, AocError> {
+        match operand {
+            0..=3 => Ok(operand),
+            4..=6 => Ok(self.registers[(operand - 4) as usize].value),
+            _ => Err(AocError::new(format!("invalid combo operand {}", operand))),
+        }
+    }
+
+    /// `A` shifted right by `operand`, which a register operand may make
+    /// wider than `A`, shifting out every bit.
+    fn division(&mut self, opcode: OpCode, operand: isize) {
+        let result = u32::try_from(operand)
+            .ok()
+            .and_then(|shift| self.registers[0].value.checked_shr(shift))
+            .unwrap_or(0);
+        match opcode {
+            OpCode::Adv => self.registers[0].value = result,
+            OpCode::Bdv => self.registers[1].value = result,
+            OpCode::Cdv => self.registers[2].value = result,
+            _ => unreachable!(),
+        }
+    }
+
+    fn output(&self) -> String {
+        self.out
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    fn reset(&mut self) {
+        self.registers[0].value = 0;
+        self.registers[1].value = 0;
+        self.registers[2].value = 0;
+        self.out.clear();
+        self.pc = 0;
+    }
+
+    fn set_registers(&mut self, a: isize, b: isize, c: isize) {
+        self.registers[0].value = a;
+        self.registers[1].value = b;
+        self.registers[2].value = c;
+    }
+}
+
+pub struct AocDay17 {
+    instructions: Vec<isize>,
+    a: isize,
+    b: isize,
+    c: isize,
+}
+
+impl AocDay17 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let (instructions, a, b, c) = parse_program(input)?;
+        Ok(AocDay17 {
+            instructions,
+            a,
+            b,
+            c,
+        })
+    }
+
+    pub fn part1(&self) -> Result<String, AocError> {
+        let mut computer = Computer::new(self.a, self.b, self.c, self.instructions.clone());
+        computer.run()?;
+        Ok(computer.output())
+    }
+
+    pub fn part2(&self) -> Result<isize, AocError> {
+        let expected_out = self
+            .instructions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(",");
+
+        // Without output every candidate matches, and their number grows
+        // eightfold with each number of the program.
+        let prints = self
+            .instructions
+            .chunks(2)
+            .any(|pair| OpCode::from(pair[0]) == Some(OpCode::Out));
+        if !prints {
+            return Err(AocError::new(
+                "the program has no `out` instruction, so it never prints itself",
+            ));
+        }
+
+        let mut combinations = vec![0];
+        let mut computer = Computer::new(0, self.b, self.c, self.instructions.clone());
+
+        for _ in 0..self.instructions.len() {
+            combinations = Self::filter_combinations(
+                &combinations,
+                &expected_out,
+                &mut computer,
+                self.b,
+                self.c,
+            )?;
+        }
+
+        // Candidates with leading zero digits only print the end of the
+        // program, so keep those that print all of it.
+        let mut lowest = None;
+        for a in combinations {
+            cancel::check()?;
+            computer.reset();
+            computer.set_registers(a, self.b, self.c);
+            computer.run()?;
+            if computer.output() == expected_out {
+                lowest = Some(lowest.map_or(a, |lowest: isize| lowest.min(a)));
+            }
+        }
+        lowest.ok_or_else(|| AocError::new("no value of A reproduces the program"))
+    }
+
+    fn filter_combinations(
+        combinations: &[isize],
+        expected_out: &str,
+        computer: &mut Computer,
+        b: isize,
+        c: isize,
+    ) -> Result<Vec<isize>, AocError> {
+        let mut new_combinations = Vec::new();
+
+        for &ah in combinations {
+            cancel::check()?;
+            for al in 0..8 {
+                let a = ah * 8 + al;
+                computer.reset();
+                computer.set_registers(a, b, c);
+                computer.run()?;
+
+                if Self::is_valid_output(&computer.output(), expected_out) {
+                    new_combinations.push(a);
+                }
+            }
+        }
+
+        Ok(new_combinations)
+    }
+
+    /// The output so far must match the end of the program, as every extra
+    /// 3 bits of `a` prepend one more output value.
+    fn is_valid_output(output: &str, instruction_str: &str) -> bool {
+        instruction_str.ends_with(output)
+    }
+
+    /// Part 2 without assuming how the program uses `A`: tries every `A` from
+    /// 1 up until the program prints itself. Only feasible for programs of up
+    /// to 8 numbers, as a program that prints one number per 3 bits of `A`
+    /// needs an `A` of nearly 8 to the power of its length.
+    fn find_quine(&self) -> Result<isize, AocError> {
+        let mut computer = Computer::new(0, self.b, self.c, self.instructions.clone());
+        let mut a = 1;
+        loop {
+            if a % CHECK_INTERVAL as isize == 0 {
+                cancel::check()?;
+            }
+            computer.reset();
+            computer.set_registers(a, self.b, self.c);
+            computer.run()?;
+            if computer.out == self.instructions {
+                return Ok(a);
+            }
+            a += 1;
+        }
+    }
+}
+
+fn parse_program(input: &str) -> Result<(Vec<isize>, isize, isize, isize), AocError> {
+    let input = utils::split_lines_whitespace(input)?;
+    let registers = parse_registers(&input.0)?;
+    let program_row = input.0.len() + 1;
+    let line = input.1.first().ok_or_else(|| {
+        AocError::at_row(program_row, "expected `Program: ...` after the registers")
+    })?;
+    let instructions = parse_instructions(program_row, line)?;
+    Ok((instructions, registers[0], registers[1], registers[2]))
+}
+
+fn parse_registers(lines: &[String]) -> Result<[isize; 3], AocError> {
+    let mut regs = [0; 3];
+
+    for (row, name) in ["A", "B", "C"].iter().enumerate() {
+        let line = lines
+            .get(row)
+            .ok_or_else(|| AocError::at_row(row, format!("expected `Register {}: ...`", name)))?;
+        let value = utils::strip_prefix(row, line, line, &format!("Register {}:", name))?;
+        regs[row] = utils::parse_field(row, line, value)?;
+    }
+
+    Ok(regs)
+}
+
+fn parse_instructions(row: usize, line: &str) -> Result<Vec<isize>, AocError> {
+    let program = utils::strip_prefix(row, line, line, "Program:")?;
+    let mut instructions = Vec::new();
+
+    for field in program.split(',') {
+        let value = utils::parse_field(row, line, field)?;
+        if !(0..=7).contains(&value) {
+            return Err(AocError::at(
+                row,
+                utils::column(line, field.trim()),
+                format!("expected a 3-bit value, found `{}`", value),
+            ));
+        }
+        // Combo operand 7 is reserved, the operand of every other opcode is
+        // a literal.
+        let combo = instructions.len() % 2 == 1
+            && instructions
+                .last()
+                .and_then(|&opcode| OpCode::from(opcode))
+                .is_some_and(OpCode::takes_combo);
+        if combo && value == 7 {
+            return Err(AocError::at(
+                row,
+                utils::column(line, field.trim()),
+                "combo operand 7 is reserved",
+            ));
+        }
+        instructions.push(value);
+    }
+
+    if instructions.len() % 2 != 0 {
+        return Err(AocError::at_row(
+            row,
+            "expected an even number of values, every opcode needs an operand",
+        ));
+    }
+
+    Ok(instructions)
+}
+
+/// Whether some `A` makes `program` print itself, searching `A` 3 bits at a
+/// time from the end of the output. Independent of `Computer`, so that a bug
+/// there does not hide in the generated inputs.
+fn reproducible(program: &[usize]) -> bool {
+    fn output(program: &[usize], a: u64) -> Vec<usize> {
+        let mut registers = [a, 0, 0];
+        let mut out = Vec::new();
+        let mut pc = 0;
+        while pc < program.len() {
+            let operand = program[pc + 1] as u64;
+            // Operand 7 is only used as a literal.
+            let combo = match operand {
+                0..=3 | 7 => operand,
+                _ => registers[operand as usize - 4],
+            };
+            match program[pc] {
+                0 => registers[0] >>= combo,
+                1 => registers[1] ^= operand,
+                2 => registers[1] = combo % 8,
+                3 if registers[0] != 0 => {
+                    pc = operand as usize;
+                    continue;
+                }
+                3 => {}
+                4 => registers[1] ^= registers[2],
+                5 => out.push((combo % 8) as usize),
+                6 => registers[1] = registers[0] >> combo,
+                _ => registers[2] = registers[0] >> combo,
+            }
+            pc += 2;
+        }
+        out
+    }
+
+    let mut candidates = vec![0];
+    for len in 1..=program.len() {
+        candidates = candidates
+            .iter()
+            .flat_map(|&high| (0..8).map(move |low| high * 8 + low))
+            .filter(|&a| output(program, a) == program[program.len() - len..])
+            .collect();
+    }
+    candidates.iter().any(|&a| a > 0)
+}
+
+/// A program of 8 numbers: the shift of `A` and one instruction that leaves
+/// `A` alone, in either order, then the output of a register and the jump
+/// back.
+fn short_program(rng: &mut Rng) -> Vec<usize> {
+    // `bdv` shifts by a literal, and so does `cdv` or by `B`, which it
+    // leaves at 0.
+    let other = match rng.below(5) {
+        0 => [1, rng.below(8)],
+        1 => [2, rng.below(7)],
+        2 => [4, rng.below(8)],
+        3 => [6, rng.below(4)],
+        _ => [7, [0, 1, 2, 3, 5][rng.below(5)]],
+    };
+    let mut instructions = [other, [0, 3]];
+    rng.shuffle(&mut instructions);
+
+    let mut program: Vec<usize> = instructions.iter().flatten().copied().collect();
+    program.extend([5, 4 + rng.below(3), 3, 0]);
+    program
+}
+
+/// A program like the puzzle's, computing the output from the low bits of
+/// `A` with 2 to 5 `operations`.
+fn puzzle_program(rng: &mut Rng, operations: usize) -> Vec<usize> {
+    let all = [
+        [2, 4],
+        [1, rng.below(8)],
+        [7, 5],
+        [1, rng.below(8)],
+        [4, rng.below(8)],
+    ];
+    // `A` only shifts after `C` is computed from it.
+    let fixed = operations.min(3);
+    let mut shuffled = all[fixed..operations].to_vec();
+    shuffled.push([0, 3]);
+    rng.shuffle(&mut shuffled);
+
+    let mut program: Vec<usize> = all[..fixed]
+        .iter()
+        .chain(&shuffled)
+        .flatten()
+        .copied()
+        .collect();
+    program.extend([5, 5, 3, 0]);
+    program
+}
+
+impl AocDay for AocDay17 {
+    type Input = AocDay17;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay17::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    fn reference(input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        (part == 2).then(|| input.find_quine().map(Answer::from))
+    }
+
+    /// Programs shaped like the puzzle's: each loop outputs a value computed
+    /// from the low bits of `A`, then shifts `A` right by 3. `size` sets the
+    /// length, up to the puzzle's 16 numbers. Below 10 the programs have 8
+    /// numbers, few enough for the reference to try every `A`. Only programs
+    /// that some `A` reproduces are kept, as part 2 expects one.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let operations = (size / 2).saturating_sub(3).min(5);
+        loop {
+            let program = if operations < 2 {
+                short_program(rng)
+            } else {
+                puzzle_program(rng, operations)
+            };
+            if reproducible(&program) {
+                let program: Vec<String> = program.iter().map(ToString::to_string).collect();
+                return Some(format!(
+                    "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                    rng.next_u64() >> 16,
+                    program.join(",")
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2, check_reference};
+
+    const EXAMPLE_1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const EXAMPLE_2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part2_answer_prints_the_whole_program() {
+        // A = 0 prints just the last instruction, which the search used to
+        // return as the lowest candidate.
+        let program = "0,3,5,4,3,0";
+        let a = AocDay17::new(&format!(
+            "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            program
+        ))
+        .unwrap()
+        .part2()
+        .unwrap();
+        assert_ne!(a, 0);
+
+        let quine = AocDay17::new(&format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            a, program
+        ))
+        .unwrap();
+        assert_eq!(quine.part1().unwrap(), program);
+    }
+
+    #[test]
+    fn reports_program_without_output() {
+        let input = AocDay17::new(
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,1,1,1,1,1,1,1,1,1,1,1,1,3,0\n",
+        )
+        .unwrap();
+        assert_eq!(
+            input.part2().unwrap_err().to_string(),
+            "the program has no `out` instruction, so it never prints itself"
+        );
+    }
+
+    #[test]
+    fn reports_program_without_quine() {
+        // Prints the octal digits of `A`, which stop before a last digit 0.
+        let input =
+            AocDay17::new("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0,3,3,0\n")
+                .unwrap();
+        assert_eq!(
+            input.part2().unwrap_err().to_string(),
+            "no value of A reproduces the program"
+        );
+    }
+
+    #[test]
+    fn divides_by_shifts_wider_than_a() {
+        // `bdv 5` sets B to A, then shifts A by B on the next loop.
+        let input = AocDay17::new(
+            "Register A: 100\nRegister B: 0\nRegister C: 0\n\nProgram: 6,5,0,5,5,4,3,0\n",
+        )
+        .unwrap();
+        assert_eq!(input.part1().unwrap(), "0");
+    }
+
+    #[test]
+    fn rejects_reserved_combo_operand() {
+        let program = |program| {
+            AocDay17::new(&format!(
+                "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                program
+            ))
+        };
+        // 7 is a literal operand of `bxl`, but a reserved combo operand of
+        // `out`.
+        assert!(program("1,7,5,4").is_ok());
+        let error = program("1,7,5,7").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 16: combo operand 7 is reserved"
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay17>(EXAMPLE_1, "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay17>(EXAMPLE_2, 117440);
+    }
+
+    #[test]
+    fn reference_example() {
+        check_reference::<AocDay17>(EXAMPLE_2, 2, 117440);
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    random::Rng,
+    utils::{self, in_bounds},
+    Answer, AocDay, AocError, Params,
+};
+use std::collections::{HashSet, VecDeque};
+
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+
+pub struct AocDay18 {
+    bytes: Vec<(usize, usize)>,
+    matrix: Vec<Vec<char>>,
+    fallen: usize,
+}
+
+impl AocDay18 {
+    pub fn new(input: &str) -> Result<AocDay18, AocError> {
+        Self::with_size(input, SIZE, FALLEN)
+    }
+
+    /// Uses a `size` by `size` memory space with the first `fallen` bytes
+    /// already down. The puzzle's examples use 7 and 12 instead of 71 and 1024.
+    pub fn with_size(input: &str, size: usize, fallen: usize) -> Result<AocDay18, AocError> {
+        let lines = utils::lines(input);
+        if lines.len() < fallen {
+            return Err(AocError::at_row(
+                lines.len(),
+                format!("expected at least {} bytes, found {}", fallen, lines.len()),
+            ));
+        }
+
+        let bytes = lines
+            .iter()
+            .enumerate()
+            .map(|(row, line)| Self::get_position(row, line, size))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut matrix = vec![vec!['.'; size]; size];
+        for pos in &bytes[..fallen] {
+            matrix[pos.1][pos.0] = '#';
+        }
+
+        Ok(AocDay18 {
+            bytes,
+            matrix,
+            fallen,
+        })
+    }
+
+    pub fn part1(&self) -> Result<isize, AocError> {
+        let matrix = self.matrix.clone();
+        let paths = Self::find_paths(&matrix);
+        let shortest = paths.iter().min_by_key(|p| p.len()).ok_or_else(|| {
+            AocError::new(format!(
+                "the first {} bytes leave no path to the exit",
+                self.fallen
+            ))
+        })?;
+
+        Ok(shortest.len() as isize - 1)
+    }
+
+    pub fn part2(&self) -> Result<String, AocError> {
+        let mut matrix = self.matrix.clone();
+
+        for &pos in &self.bytes[self.fallen..] {
+            matrix[pos.1][pos.0] = '#';
+
+            let paths = Self::find_paths(&matrix);
+            if paths.is_empty() {
+                return Ok(format!("{}, {}", pos.0, pos.1));
+            }
+        }
+
+        Err(AocError::new("no byte blocks the path to the exit"))
+    }
+
+    fn find_paths(matrix: &[Vec<char>]) -> Vec<Vec<(isize, isize)>> {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut paths = Vec::new();
+
+        queue.push_back(vec![(0, 0)]);
+        visited.insert((0, 0));
+
+        while let Some(path) = queue.pop_front() {
+            let (row, col) = path.last().unwrap();
+
+            if !in_bounds(matrix, *row, *col) || matrix[*row as usize][*col as usize] == '#' {
+                continue;
+            }
+
+            if *row == (matrix.len() - 1) as isize && *col == (matrix[0].len() - 1) as isize {
+                paths.push(path.clone());
+                continue;
+            }
+
+            for &(row_offset, col_offset) in &[(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                let new_row = row + row_offset;
+                let new_col = col + col_offset;
+
+                if visited.contains(&(new_row, new_col)) {
+                    continue;
+                }
+
+                let mut new_path = path.clone();
+                new_path.push((new_row, new_col));
+
+                queue.push_back(new_path);
+                visited.insert((new_row, new_col));
+            }
+        }
+
+        paths
+    }
+
+    fn get_position(row: usize, line: &str, size: usize) -> Result<(usize, usize), AocError> {
+        let [x, y] = utils::fields(row, line, line, line.split(','))?;
+        let pos = (
+            utils::parse_field(row, line, x)?,
+            utils::parse_field(row, line, y)?,
+        );
+
+        if pos.0 >= size || pos.1 >= size {
+            return Err(AocError::at_row(
+                row,
+                format!(
+                    "byte {},{} is outside the {}x{} grid",
+                    pos.0, pos.1, size, size
+                ),
+            ));
+        }
+
+        Ok(pos)
+    }
+
+    /// Bytes falling on a `space` by `space` grid: the first `fallen` leave a
+    /// path to the exit, and `size` more fall after the first one that blocks
+    /// it.
+    fn random_bytes(rng: &mut Rng, size: usize, space: usize, fallen: usize) -> String {
+        let mut bytes: Vec<(usize, usize)> = (0..space * space)
+            .map(|i| (i % space, i / space))
+            .filter(|&(x, y)| (x, y) != (0, 0) && (x, y) != (space - 1, space - 1))
+            .collect();
+
+        // The number of bytes after which the exit is blocked.
+        let mut blocked = bytes.len();
+        for _ in 0..100 {
+            rng.shuffle(&mut bytes);
+            let (mut open, mut closed) = (0, bytes.len() + 1);
+            while closed - open > 1 {
+                let mid = (open + closed) / 2;
+                if Self::exit_reachable(space, &bytes[..mid]) {
+                    open = mid;
+                } else {
+                    closed = mid;
+                }
+            }
+            blocked = closed;
+            if blocked > fallen {
+                break;
+            }
+        }
+
+        bytes
+            .iter()
+            .take(blocked + size)
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    }
+
+    fn exit_reachable(space: usize, bytes: &[(usize, usize)]) -> bool {
+        let corrupted: HashSet<_> = bytes.iter().copied().collect();
+        let mut seen = HashSet::from([(0, 0)]);
+        let mut queue = VecDeque::from([(0, 0)]);
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) == (space - 1, space - 1) {
+                return true;
+            }
+            let neighbours = [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ];
+            for next in neighbours {
+                if next.0 < space
+                    && next.1 < space
+                    && !corrupted.contains(&next)
+                    && seen.insert(next)
+                {
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+}
+
+impl AocDay for AocDay18 {
+    type Input = AocDay18;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay18::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        AocDay18::with_size(
+            input,
+            params.get_at_least("size", SIZE, 1)?,
+            params.get("fallen", FALLEN)?,
+        )
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(AocDay18::random_bytes(rng, size, SIZE, FALLEN))
+    }
+
+    fn generate_with(rng: &mut Rng, size: usize, params: &Params) -> Option<String> {
+        // Invalid sizes fail the parse instead.
+        let space = params.get_at_least("size", SIZE, 1).unwrap_or(SIZE);
+        let fallen = params.get("fallen", FALLEN).unwrap_or(FALLEN);
+        Some(AocDay18::random_bytes(rng, size, space, fallen))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn part1_example() {
+        // The example uses a 7x7 memory space with 12 bytes already down.
+        let input = AocDay18::with_size(EXAMPLE, 7, 12).unwrap();
+        assert_eq!(input.part1().unwrap(), 22);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = AocDay18::with_size(EXAMPLE, 7, 12).unwrap();
+        assert_eq!(input.part2().unwrap(), "6, 1");
+    }
+
+    #[test]
+    fn reports_unreachable_and_unblocked_exits() {
+        // The first byte walls off the exit of a 2x2 space, and no byte ever
+        // blocks a 3x3 one.
+        let walled = AocDay18::with_size("1,0\n0,1\n", 2, 2).unwrap();
+        assert_eq!(
+            walled.part1().unwrap_err().to_string(),
+            "the first 2 bytes leave no path to the exit"
+        );
+        let open = AocDay18::with_size("1,0\n", 3, 0).unwrap();
+        assert_eq!(
+            open.part2().unwrap_err().to_string(),
+            "no byte blocks the path to the exit"
+        );
+    }
+}
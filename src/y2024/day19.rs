@@ -0,0 +1,178 @@
+use crate::{random::Rng, utils::split_lines_whitespace, Answer, AocDay, AocError};
+use std::collections::{HashMap, HashSet};
+
+pub struct AocDay19 {
+    towels: HashSet<String>,
+    designs: Vec<String>,
+}
+
+impl AocDay19 {
+    pub fn new(input: &str) -> Result<AocDay19, AocError> {
+        let input = split_lines_whitespace(input)?;
+        if input.0.len() != 1 || input.1.is_empty() {
+            return Err(AocError::at_row(
+                input.0.len().min(1),
+                "expected one line of towels, a blank line, then the designs",
+            ));
+        }
+
+        let towels = input.0[0]
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .collect::<HashSet<String>>();
+        let designs = input.1;
+
+        Ok(AocDay19 { towels, designs })
+    }
+
+    pub fn part1(&self) -> usize {
+        let mut count = 0;
+        let mut memo = HashMap::new();
+
+        for design in &self.designs {
+            if self.split(design, &mut memo) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    pub fn part2(&self) -> usize {
+        let mut total = 0;
+
+        for design in &self.designs {
+            let mut memo = HashMap::new();
+            let mut count = 0;
+
+            self.split_count(design, &mut count, &mut memo);
+
+            total += count;
+        }
+
+        total
+    }
+
+    fn split(&self, design: &str, memo: &mut HashMap<String, bool>) -> bool {
+        if design.is_empty() {
+            return true;
+        }
+
+        if let Some(&valid) = memo.get(design) {
+            return valid;
+        }
+
+        for i in 1..design.len() + 1 {
+            let part = &design[..i];
+            if self.towels.contains(part) && self.split(&design[i..], memo) {
+                memo.insert(design.to_string(), true);
+                return true;
+            }
+        }
+
+        memo.insert(design.to_string(), false);
+        false
+    }
+
+    fn split_count(&self, design: &str, count: &mut usize, memo: &mut HashMap<String, usize>) {
+        if design.is_empty() {
+            *count += 1;
+            return;
+        }
+
+        if let Some(&cached_count) = memo.get(design) {
+            *count += cached_count;
+            return;
+        }
+
+        let mut current_count = 0;
+
+        for i in 1..=design.len() {
+            let part = &design[..i];
+            if self.towels.contains(part) {
+                self.split_count(&design[i..], &mut current_count, memo);
+            }
+        }
+
+        memo.insert(design.to_string(), current_count);
+        *count += current_count;
+    }
+}
+
+impl AocDay for AocDay19 {
+    type Input = AocDay19;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay19::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    /// Half the designs are made of towels, the rest are random stripes.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+        fn stripes(rng: &mut Rng, len: usize) -> String {
+            (0..len).map(|_| *rng.pick(&COLOURS)).collect()
+        }
+
+        let mut towels: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let len = rng.range(1..9) as usize;
+                stripes(rng, len)
+            })
+            .collect();
+        towels.sort();
+        towels.dedup();
+        rng.shuffle(&mut towels);
+
+        let mut input = towels.join(", ") + "\n\n";
+        for _ in 0..size.max(1) {
+            let len = rng.range(5..61) as usize;
+            let mut design = String::new();
+            if rng.chance(0.5) {
+                while design.len() < len {
+                    design += rng.pick(&towels).as_str();
+                }
+            } else {
+                design = stripes(rng, len);
+            }
+            input += &(design + "\n");
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay19>(EXAMPLE, 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay19>(EXAMPLE, 16);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    random::{self, Rng},
+    utils::{in_bounds, matrix},
+    Answer, AocDay, AocError, Params,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    vec,
+};
+
+type Point = (isize, isize);
+
+/// The fewest picoseconds a cheat must save to be counted.
+const MIN_SAVED: isize = 100;
+
+/// The longest cheat allowed in each part, in picoseconds.
+const CHEATS: [isize; 2] = [2, 20];
+
+pub struct AocDay20 {
+    matrix: Vec<Vec<char>>,
+    start: (isize, isize),
+    end: (isize, isize),
+    min_saved: isize,
+    cheats: [isize; 2],
+}
+
+impl AocDay20 {
+    pub fn new(input: &str) -> Result<AocDay20, AocError> {
+        Self::with_threshold(input, MIN_SAVED)
+    }
+
+    /// Counts only cheats that save at least `min_saved` picoseconds. The
+    /// puzzle's examples use smaller thresholds than the real input.
+    pub fn with_threshold(input: &str, min_saved: isize) -> Result<AocDay20, AocError> {
+        Self::with_cheats(input, min_saved, CHEATS)
+    }
+
+    /// Like `with_threshold`, also allowing cheats of up to `cheats[0]`
+    /// picoseconds in part 1 and `cheats[1]` in part 2.
+    pub fn with_cheats(
+        input: &str,
+        min_saved: isize,
+        cheats: [isize; 2],
+    ) -> Result<AocDay20, AocError> {
+        let matrix = matrix(input)?;
+        let (start, end) = Self::find_start_end(&matrix)?;
+
+        Ok(AocDay20 {
+            matrix,
+            start,
+            end,
+            min_saved,
+            cheats,
+        })
+    }
+
+    pub fn part1(&self) -> Result<usize, AocError> {
+        self.calc_saved_steps(self.cheats[0], self.min_saved)
+    }
+
+    pub fn part2(&self) -> Result<usize, AocError> {
+        self.calc_saved_steps(self.cheats[1], self.min_saved)
+    }
+
+    fn calc_saved_steps(&self, max_cheats: isize, min_saved: isize) -> Result<usize, AocError> {
+        let path = self.get_path()?;
+        let mut freq = HashMap::new();
+
+        for (i, &current) in path.iter().enumerate() {
+            for (j, &next) in path.iter().enumerate().skip(i + 1) {
+                let direct_dist = Self::manhattan((current.0, current.1), (next.0, next.1));
+                let path_dist = j - i;
+
+                if direct_dist <= max_cheats {
+                    let saved = path_dist as isize - direct_dist;
+                    *freq.entry(saved).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Ok(freq
+            .iter()
+            .filter(|(&k, _)| k >= min_saved)
+            .map(|(_, &v)| v)
+            .sum())
+    }
+
+    fn get_path(&self) -> Result<Vec<(isize, isize)>, AocError> {
+        let paths = Self::find_paths(&self.matrix, self.start, self.end);
+        let shortest = paths
+            .iter()
+            .min_by_key(|p| p.len())
+            .ok_or_else(|| AocError::new("no track leads from `S` to `E`"))?;
+        Ok(shortest.clone())
+    }
+
+    fn manhattan(a: (isize, isize), b: (isize, isize)) -> isize {
+        (a.0 - b.0).abs() + (a.1 - b.1).abs()
+    }
+
+    fn find_paths(
+        matrix: &[Vec<char>],
+        start: (isize, isize),
+        end: (isize, isize),
+    ) -> Vec<Vec<(isize, isize)>> {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut paths = Vec::new();
+
+        queue.push_back(vec![start]);
+        visited.insert(start);
+
+        while let Some(path) = queue.pop_front() {
+            let (row, col) = path.last().unwrap();
+
+            if !in_bounds(matrix, *row, *col) || matrix[*row as usize][*col as usize] == '#' {
+                continue;
+            }
+
+            if *row == end.0 && *col == end.1 {
+                paths.push(path.clone());
+                continue;
+            }
+
+            for &(row_offset, col_offset) in &[(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                let new_row = row + row_offset;
+                let new_col = col + col_offset;
+
+                if visited.contains(&(new_row, new_col)) {
+                    continue;
+                }
+
+                let mut new_path = path.clone();
+                new_path.push((new_row, new_col));
+
+                queue.push_back(new_path);
+                visited.insert((new_row, new_col));
+            }
+        }
+
+        paths
+    }
+
+    fn find_start_end(matrix: &[Vec<char>]) -> Result<(Point, Point), AocError> {
+        let mut start = None;
+        let mut end = None;
+
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                if *c == 'S' {
+                    start = Some((i as isize, j as isize));
+                } else if *c == 'E' {
+                    end = Some((i as isize, j as isize));
+                }
+            }
+        }
+
+        match (start, end) {
+            (Some(start), Some(end)) => Ok((start, end)),
+            (None, _) => Err(AocError::new("expected a start `S` on the map")),
+            (_, None) => Err(AocError::new("expected an end `E` on the map")),
+        }
+    }
+}
+
+impl AocDay for AocDay20 {
+    type Input = AocDay20;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay20::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        let cheats = [
+            params.get_at_least("part1_cheat", CHEATS[0], 0)?,
+            params.get_at_least("part2_cheat", CHEATS[1], 0)?,
+        ];
+        AocDay20::with_cheats(input, params.get("min_saved", MIN_SAVED)?, cheats)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    /// The path between two corners of a maze, which like the puzzle's track
+    /// has no branches and walls between all its turns.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(5) | 1;
+        let maze = random::maze(rng, side, side);
+        let (start, end) = ((1, 1), (side - 2, side - 2));
+
+        let mut previous = HashMap::from([(start, start)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some((r, c)) = queue.pop_front() {
+            for next in [(r + 1, c), (r - 1, c), (r, c + 1), (r, c - 1)] {
+                if maze[next.0][next.1] == '.' && !previous.contains_key(&next) {
+                    previous.insert(next, (r, c));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut track = vec![vec!['#'; side]; side];
+        let mut cell = end;
+        while cell != start {
+            track[cell.0][cell.1] = '.';
+            cell = previous[&cell];
+        }
+        track[start.0][start.1] = 'S';
+        track[end.0][end.1] = 'E';
+        Some(random::grid_text(&track))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn pairs_path_positions_without_overflow() {
+        // No cheat in the example saves 100 picoseconds. Subtracting a later
+        // path index from an earlier one used to overflow here in debug builds.
+        let input = AocDay20::new(EXAMPLE).unwrap();
+        assert_eq!(input.part1().unwrap(), 0);
+        assert_eq!(input.part2().unwrap(), 0);
+    }
+
+    #[test]
+    fn reports_missing_track() {
+        let input = AocDay20::new("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(
+            input.part1().unwrap_err().to_string(),
+            "no track leads from `S` to `E`"
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        // The example race is too short for cheats saving 100 picoseconds.
+        let input = AocDay20::with_threshold(EXAMPLE, 64).unwrap();
+        assert_eq!(input.part1().unwrap(), 1);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = AocDay20::with_threshold(EXAMPLE, 76).unwrap();
+        assert_eq!(input.part2().unwrap(), 3);
+    }
+}
@@ -3,7 +3,10 @@ use std::{
     vec,
 };
 
-use crate::AocDay;
+use crate::{random::Rng, Answer, AocDay, AocError, Params};
+
+/// How many directional keypad robots are in the chain in each part.
+const ROBOTS: [usize; 2] = [2, 25];
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct Position(isize, isize);
@@ -12,13 +15,21 @@ pub struct AocDay21 {
     codes: Vec<String>,
     num_keypad: HashMap<char, Position>,
     dir_keypad: HashMap<char, Position>,
+    robots: [usize; 2],
 }
 
 impl AocDay21 {
-    pub fn new() -> Self {
-        let lines = crate::utils::read_lines("input/day21.txt");
-        AocDay21 {
-            codes: lines,
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Self::with_robots(input, ROBOTS)
+    }
+
+    /// Chains `robots[0]` directional keypad robots in part 1 and `robots[1]`
+    /// in part 2.
+    pub fn with_robots(input: &str, robots: [usize; 2]) -> Result<Self, AocError> {
+        let codes = crate::utils::lines(input);
+        let day = AocDay21 {
+            codes,
+            robots,
             num_keypad: HashMap::from([
                 ('7', Position(0, 0)),
                 ('8', Position(0, 1)),
@@ -39,15 +50,34 @@ impl AocDay21 {
                 ('v', Position(1, 1)),
                 ('>', Position(1, 2)),
             ]),
+        };
+
+        for (row, code) in day.codes.iter().enumerate() {
+            if let Some((col, c)) = code
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !day.num_keypad.contains_key(c))
+            {
+                return Err(AocError::at(
+                    row,
+                    col,
+                    format!("expected a keypad button `0-9` or `A`, found `{}`", c),
+                ));
+            }
+            if !code.chars().any(|c| c.is_ascii_digit()) {
+                return Err(AocError::at_row(row, "expected a code with a numeric part"));
+            }
         }
+
+        Ok(day)
     }
 
     pub fn part1(&self) -> isize {
-        self.calc_code_len(2)
+        self.calc_code_len(self.robots[0])
     }
 
     pub fn part2(&self) -> isize {
-        self.calc_code_len(25)
+        self.calc_code_len(self.robots[1])
     }
 
     fn calc_code_len(&self, level: usize) -> isize {
@@ -60,7 +90,7 @@ impl AocDay21 {
             for c in code.chars() {
                 let target_pos = self.num_keypad[&c];
                 let paths = Self::get_paths(current_pos, self.num_keypad[&c], &self.num_keypad);
-                let mut min_path_len = std::usize::MAX;
+                let mut min_path_len = usize::MAX;
 
                 for path in paths {
                     let path_len = Self::calc_level_len(&path, &self.dir_keypad, level, &mut memo);
@@ -96,9 +126,9 @@ impl AocDay21 {
         let mut code_len = 0;
 
         for c in path {
-            let target_pos = keypad[&c];
-            let paths = Self::get_paths(current_pos, keypad[&c], &keypad);
-            let mut min_len = std::usize::MAX;
+            let target_pos = keypad[c];
+            let paths = Self::get_paths(current_pos, keypad[c], keypad);
+            let mut min_len = usize::MAX;
 
             for path in &paths {
                 let path_len = Self::calc_level_len(path, keypad, level - 1, memo);
@@ -163,18 +193,58 @@ impl AocDay21 {
         paths
     }
 
-    fn extract_num_part(code: &Vec<char>) -> isize {
+    fn extract_num_part(code: &[char]) -> isize {
         let numeric_part: String = code.iter().filter(|c| c.is_numeric()).collect();
         numeric_part.parse().unwrap()
     }
 }
 
 impl AocDay for AocDay21 {
-    fn part1(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part1())
+    type Input = AocDay21;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay21::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        let robots = [
+            params.get("part1_robots", ROBOTS[0])?,
+            params.get("part2_robots", ROBOTS[1])?,
+        ];
+        AocDay21::with_robots(input, robots)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2().into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let codes: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{:03}A\n", rng.below(1000)))
+            .collect();
+        Some(codes.concat())
     }
+}
 
-    fn part2(&self) -> Box<dyn std::fmt::Display> {
-        Box::new(self.part2())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_part1;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay21>(EXAMPLE, 126384);
     }
 }
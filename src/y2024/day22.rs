@@ -0,0 +1,194 @@
+use crate::{
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError, Params,
+};
+use std::collections::HashMap;
+
+const MODULO: isize = 16777216;
+const MULTIPLIER_1: isize = 64;
+const MULTIPLIER_2: isize = 2048;
+const DIVISOR: isize = 32;
+/// How many new secrets each buyer generates.
+const ITERATIONS: usize = 2000;
+
+pub struct AocDay22 {
+    nums: Vec<isize>,
+    iterations: usize,
+}
+
+impl AocDay22 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Self::with_iterations(input, ITERATIONS)
+    }
+
+    pub fn with_iterations(input: &str, iterations: usize) -> Result<Self, AocError> {
+        Ok(AocDay22 {
+            iterations,
+            nums: lines(input)
+                .iter()
+                .enumerate()
+                .map(|(row, line)| utils::parse_field(row, line, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn part1(&self) -> isize {
+        let mut memo = HashMap::new();
+        self.nums
+            .iter()
+            .map(|&num| Self::last_secret(num, self.iterations, &mut memo))
+            .sum()
+    }
+
+    /// The most bananas any sequence of four price changes buys. Fails if no
+    /// buyer sees four changes, as with fewer than 5 iterations.
+    pub fn part2(&self) -> Result<isize, AocError> {
+        let mut sequences_map = HashMap::new();
+
+        for num in &self.nums {
+            let mut memo = HashMap::new();
+            let mut secrets = vec![];
+
+            Self::generate_secrets(*num, self.iterations, &mut memo, &mut secrets);
+
+            let prices = secrets.iter().map(|&s| s % 10).collect::<Vec<_>>();
+            let level = Self::extract_sequences(&prices).into_iter().fold(
+                HashMap::new(),
+                |mut acc, (pattern, value)| {
+                    acc.entry(pattern).or_insert_with(Vec::new).push(value);
+                    acc
+                },
+            );
+
+            for (pattern, values) in level {
+                sequences_map
+                    .entry(pattern)
+                    .or_insert_with(Vec::new)
+                    .push(values[0]);
+            }
+        }
+
+        sequences_map
+            .values()
+            .map(|s| s.iter().sum::<isize>())
+            .max()
+            .ok_or_else(|| AocError::new("no buyer's prices change four times in a row"))
+    }
+
+    fn last_secret(
+        secret: isize,
+        iterations: usize,
+        memo: &mut HashMap<(isize, usize), isize>,
+    ) -> isize {
+        let mut secrets = vec![];
+        Self::generate_secrets(secret, iterations, memo, &mut secrets);
+        *secrets.last().unwrap()
+    }
+
+    fn generate_secrets(
+        secret: isize,
+        depth: usize,
+        memo: &mut HashMap<(isize, usize), isize>,
+        nums: &mut Vec<isize>,
+    ) {
+        if depth == 0 {
+            return;
+        }
+
+        if let Some(&result) = memo.get(&(secret, depth)) {
+            nums.push(result);
+            return;
+        }
+
+        let next = Self::next(secret);
+        nums.push(next);
+
+        Self::generate_secrets(next, depth - 1, memo, nums);
+        memo.insert((secret, depth), next);
+    }
+
+    fn next(mut secret: isize) -> isize {
+        secret = secret ^ ((secret * MULTIPLIER_1) % MODULO);
+        secret = secret ^ ((secret / DIVISOR) % MODULO);
+        secret = secret ^ ((secret * MULTIPLIER_2) % MODULO);
+        secret
+    }
+
+    fn extract_sequences(digits: &[isize]) -> Vec<((isize, isize, isize, isize), isize)> {
+        digits
+            .windows(5)
+            .map(|w| ((w[1] - w[0], w[2] - w[1], w[3] - w[2], w[4] - w[3]), w[4]))
+            .collect()
+    }
+}
+
+impl AocDay for AocDay22 {
+    type Input = AocDay22;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay22::new(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, AocError> {
+        AocDay22::with_iterations(input, params.get_at_least("iterations", ITERATIONS, 1)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let secrets: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{}\n", rng.range(1..16777216)))
+            .collect();
+        Some(secrets.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE_1: &str = "\
+1
+10
+100
+2024
+";
+
+    const EXAMPLE_2: &str = "\
+1
+2
+3
+2024
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay22>(EXAMPLE_1, 37327623);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay22>(EXAMPLE_2, 23);
+    }
+
+    #[test]
+    fn part2_needs_four_price_changes() {
+        for iterations in 1..=4 {
+            let input = AocDay22::with_iterations(EXAMPLE_2, iterations).unwrap();
+            assert_eq!(
+                input.part2().unwrap_err().to_string(),
+                "no buyer's prices change four times in a row"
+            );
+        }
+        let input = AocDay22::with_iterations(EXAMPLE_2, 5).unwrap();
+        assert!(input.part2().is_ok());
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{
+    cancel,
+    random::Rng,
+    utils::{self, lines},
+    Answer, AocDay, AocError,
+};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    vec,
+};
+
+/// How many random graphs `generate` tries for one with a single largest
+/// party.
+const GENERATE_ATTEMPTS: usize = 20;
+
+/// How many parties `generate` may list in one graph while looking for its
+/// largest ones.
+const UNIQUE_CHECK_STEPS: usize = 100_000;
+
+pub struct AocDay23 {
+    graph: HashMap<String, HashSet<String>>,
+}
+
+impl AocDay23 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        Ok(AocDay23 {
+            graph: Self::build_graph(input)?,
+        })
+    }
+
+    pub fn part1(&self) -> usize {
+        self.get_triplets()
+            .iter()
+            .filter(|t| t.iter().any(|s| s.starts_with('t')))
+            .count()
+    }
+
+    pub fn part2(&self) -> Result<String, AocError> {
+        let groups = self.get_connected_groups();
+        let passwords = self.generate_passwords(&groups);
+        // A computer in no triangle has an empty group.
+        passwords
+            .into_iter()
+            .filter(|p| !p.is_empty())
+            .max_by_key(|p| p.len())
+            .ok_or_else(|| AocError::new("no LAN party of three or more computers"))
+    }
+
+    fn build_graph(input: &str) -> Result<HashMap<String, HashSet<String>>, AocError> {
+        let lines = lines(input);
+        let mut graph = HashMap::new();
+
+        for (row, line) in lines.iter().enumerate() {
+            let [from, to] = utils::fields(row, line, line, line.split('-'))?;
+            let from = from.to_string();
+            let to = to.to_string();
+
+            graph
+                .entry(from.clone())
+                .or_insert(HashSet::new())
+                .insert(to.clone());
+            graph
+                .entry(to.clone())
+                .or_insert(HashSet::new())
+                .insert(from.clone());
+        }
+
+        Ok(graph)
+    }
+
+    fn get_triplets(&self) -> HashSet<Vec<String>> {
+        let mut triplets = HashSet::new();
+        for (node, neighbors) in &self.graph {
+            let neighbors = neighbors.iter().collect::<Vec<_>>();
+            for (i, a) in neighbors.iter().enumerate() {
+                for b in &neighbors[i + 1..] {
+                    if self.graph.get(*a).is_some_and(|n| n.contains(*b)) {
+                        let mut triplet = vec![node.clone(), (*a).clone(), (*b).clone()];
+                        triplet.sort();
+                        triplets.insert(triplet);
+                    }
+                }
+            }
+        }
+        triplets
+    }
+
+    fn get_connected_groups(&self) -> Vec<HashSet<String>> {
+        let mut groups = vec![];
+        for (node, neighbors) in &self.graph {
+            let neighbors = neighbors.iter().collect::<Vec<_>>();
+            let mut group = HashSet::new();
+
+            for (i, a) in neighbors.iter().enumerate() {
+                for b in &neighbors[i + 1..] {
+                    if self.graph.get(*a).is_some_and(|n| n.contains(*b)) {
+                        group.insert(node.clone());
+                        group.insert((*a).clone());
+                        group.insert((*b).clone());
+                    }
+                }
+            }
+
+            groups.push(group);
+        }
+        groups
+    }
+
+    fn is_fully_connected(&self, group: &HashSet<String>) -> bool {
+        for a in group.iter() {
+            for b in group.iter() {
+                if a != b && !self.graph.get(a).unwrap().contains(b) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn generate_passwords(&self, groups: &[HashSet<String>]) -> HashSet<String> {
+        let mut passwords = HashSet::new();
+        for group in groups.iter() {
+            if self.is_fully_connected(group) {
+                let mut password = group.clone().iter().cloned().collect::<Vec<String>>();
+
+                password.sort();
+                passwords.insert(password.join(","));
+            }
+        }
+        passwords
+    }
+
+    /// Part 1 by checking every set of three computers.
+    fn count_triangles(&self) -> usize {
+        let mut names: Vec<&String> = self.graph.keys().collect();
+        names.sort();
+        let connected = |a: &String, b: &String| self.graph[a].contains(b);
+
+        let mut count = 0;
+        for (i, a) in names.iter().enumerate() {
+            for (j, b) in names.iter().enumerate().skip(i + 1) {
+                for c in names.iter().skip(j + 1) {
+                    if connected(a, b)
+                        && connected(a, c)
+                        && connected(b, c)
+                        && [a, b, c].iter().any(|name| name.starts_with('t'))
+                    {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Part 2 by listing every set of computers that are all connected to
+    /// each other, failing if more than one is the largest.
+    fn unique_largest_party(&self) -> Result<String, AocError> {
+        self.unique_largest_party_within(usize::MAX)
+    }
+
+    /// Like `unique_largest_party`, but gives up after listing `steps`
+    /// parties.
+    fn unique_largest_party_within(&self, mut steps: usize) -> Result<String, AocError> {
+        fn extend<'a>(
+            graph: &HashMap<String, HashSet<String>>,
+            names: &[&'a String],
+            party: &mut Vec<&'a String>,
+            next: usize,
+            largest: &mut Vec<Vec<&'a String>>,
+            steps: &mut usize,
+        ) -> Result<(), AocError> {
+            cancel::check()?;
+            *steps = steps
+                .checked_sub(1)
+                .ok_or_else(|| AocError::new("too many parties to list"))?;
+            match largest.first().map(Vec::len) {
+                Some(len) if len > party.len() => {}
+                Some(len) if len == party.len() => largest.push(party.clone()),
+                _ => *largest = vec![party.clone()],
+            }
+
+            for (i, name) in names.iter().enumerate().skip(next) {
+                if party.iter().all(|member| graph[*member].contains(*name)) {
+                    party.push(name);
+                    extend(graph, names, party, i + 1, largest, steps)?;
+                    party.pop();
+                }
+            }
+            Ok(())
+        }
+
+        let mut names: Vec<&String> = self.graph.keys().collect();
+        names.sort();
+        let mut largest = Vec::new();
+        extend(
+            &self.graph,
+            &names,
+            &mut Vec::new(),
+            0,
+            &mut largest,
+            &mut steps,
+        )?;
+
+        let passwords: Vec<String> = largest
+            .iter()
+            .map(|party| {
+                party
+                    .iter()
+                    .map(|name| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
+        match &passwords[..] {
+            [password] => Ok(password.clone()),
+            _ => Err(AocError::new(format!(
+                "expected one largest LAN party, found {}: {}",
+                passwords.len(),
+                passwords.join(" and ")
+            ))),
+        }
+    }
+}
+
+impl AocDay for AocDay23 {
+    type Input = AocDay23;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay23::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    fn reference(input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(match part {
+            1 => Ok(input.count_triangles().into()),
+            _ => input.unique_largest_party().map(Answer::from),
+        })
+    }
+
+    /// A graph of `size` computers with one planted LAN party among random
+    /// connections. Graphs with several largest parties are dropped, as the
+    /// puzzle's password is unique. Telling them apart lists every party, so
+    /// each check gives up after `UNIQUE_CHECK_STEPS` parties; after
+    /// `GENERATE_ATTEMPTS` graphs the other computers are only chained
+    /// together, which cannot tie the party.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut names: Vec<String> = ('a'..='z')
+            .flat_map(|a| ('a'..='z').map(move |b| format!("{}{}", a, b)))
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(4, names.len()));
+
+        let mut order: Vec<usize> = (0..names.len()).collect();
+        rng.shuffle(&mut order);
+        let (party, others) = order.split_at((names.len() / 2).clamp(3, 13));
+        let mut planted = BTreeSet::new();
+        for &a in party {
+            for &b in party {
+                connect(&mut planted, a, b);
+            }
+        }
+
+        for _ in 0..GENERATE_ATTEMPTS {
+            let mut connections = planted.clone();
+            for _ in 0..names.len() {
+                connect(
+                    &mut connections,
+                    rng.below(names.len()),
+                    rng.below(names.len()),
+                );
+            }
+            let input = connections_text(rng, &names, connections);
+            let unique = AocDay23::new(&input)
+                .is_ok_and(|day| day.unique_largest_party_within(UNIQUE_CHECK_STEPS).is_ok());
+            if unique {
+                return Some(input);
+            }
+        }
+
+        let mut connections = planted;
+        for pair in [party[0]]
+            .iter()
+            .chain(others)
+            .collect::<Vec<_>>()
+            .windows(2)
+        {
+            connect(&mut connections, *pair[0], *pair[1]);
+        }
+        Some(connections_text(rng, &names, connections))
+    }
+}
+
+/// Connects two computers, if they are not the same one.
+fn connect(connections: &mut BTreeSet<(usize, usize)>, a: usize, b: usize) {
+    if a != b {
+        connections.insert((a.min(b), a.max(b)));
+    }
+}
+
+/// One line per connection, each written either way round, in random order.
+/// The connections are drawn for in sorted order, so that the text only
+/// depends on the seed.
+fn connections_text(
+    rng: &mut Rng,
+    names: &[String],
+    connections: BTreeSet<(usize, usize)>,
+) -> String {
+    let mut lines: Vec<String> = connections
+        .into_iter()
+        .map(|(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}\n", names[a], names[b]),
+            false => format!("{}-{}\n", names[b], names[a]),
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2, check_reference};
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay23>(EXAMPLE, 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        check_part2::<AocDay23>(EXAMPLE, "co,de,ka,ta");
+    }
+
+    #[test]
+    fn part2_reports_missing_party() {
+        for input in ["", "ab-cd\ncd-ef\n"] {
+            let input = AocDay23::new(input).unwrap();
+            assert_eq!(
+                input.part2().unwrap_err().to_string(),
+                "no LAN party of three or more computers"
+            );
+        }
+    }
+
+    #[test]
+    fn reference_example() {
+        check_reference::<AocDay23>(EXAMPLE, 1, 7);
+        check_reference::<AocDay23>(EXAMPLE, 2, "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    random::Rng,
+    utils::{self, split_lines_whitespace},
+    Answer, AocDay, AocError,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, Clone)]
+struct Wire {
+    value: Option<u16>,
+}
+
+impl Wire {
+    fn new(value: Option<u16>) -> Self {
+        Self { value }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Gate {
+    And,
+    Or,
+    Xor,
+}
+
+impl Gate {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::And => "AND",
+            Self::Or => "OR",
+            Self::Xor => "XOR",
+        }
+    }
+
+    fn apply(&self, a: u16, b: u16) -> u16 {
+        match self {
+            Self::And => a & b,
+            Self::Or => a | b,
+            Self::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Connection {
+    input: [String; 2],
+    gate: Gate,
+    output: String,
+}
+
+impl Connection {
+    fn new(input1: String, input2: String, gate: Gate, output: String) -> Self {
+        Self {
+            input: [input1, input2],
+            gate,
+            output,
+        }
+    }
+
+    fn matches(&self, left: &str, right: &str, gate: &Gate) -> bool {
+        ((self.input[0] == left && self.input[1] == right)
+            || (self.input[0] == right && self.input[1] == left))
+            && self.gate == *gate
+    }
+}
+
+pub struct AocDay24 {
+    wires: HashMap<String, Wire>,
+    connections: Vec<Connection>,
+}
+
+impl AocDay24 {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let (wires, connections) = Self::parse_input(input)?;
+        Ok(Self { wires, connections })
+    }
+
+    pub fn part1(&self) -> Result<usize, AocError> {
+        let (mut wires, mut connections) = (self.wires.clone(), self.connections.clone());
+        Self::simulate(&mut wires, &mut connections);
+
+        let mut z_wires = wires
+            .iter()
+            .filter(|(k, _)| k.starts_with("z"))
+            .collect::<Vec<_>>();
+        z_wires.sort_by(|a, b| a.0.cmp(b.0));
+
+        z_wires
+            .iter()
+            .rev()
+            .try_fold(0usize, |number, (name, wire)| {
+                let bit = wire
+                    .value
+                    .ok_or_else(|| AocError::new(format!("wire `{}` never gets a value", name)))?;
+                number
+                    .checked_mul(2)
+                    .map(|number| number + bit as usize)
+                    .ok_or_else(|| AocError::new("the `z` wires hold too many bits for a number"))
+            })
+    }
+
+    pub fn part2(&self) -> Result<String, AocError> {
+        let mut connections = self.connections.clone();
+        let mut swaps = HashSet::new();
+
+        while let Some((a, b)) = Self::find_swap(&connections)? {
+            Self::swap_outputs(&mut connections, &a, &b);
+            if !swaps.insert((a, b)) {
+                return Err(AocError::new(
+                    "swapping gate outputs does not repair the adder",
+                ));
+            }
+        }
+
+        let mut flat: Vec<_> = swaps.iter().flat_map(|(a, b)| [a, b]).cloned().collect();
+
+        flat.sort();
+        Ok(flat.join(","))
+    }
+
+    fn simulate(wires: &mut HashMap<String, Wire>, connections: &mut [Connection]) {
+        let mut queue = VecDeque::new();
+
+        fn enqueue(
+            queue: &mut VecDeque<usize>,
+            connections: &[Connection],
+            wires: &HashMap<String, Wire>,
+        ) {
+            for (idx, connection) in connections.iter().enumerate() {
+                if wires[connection.output.as_str()].value.is_none()
+                    && connection.input.iter().all(|w| wires[w].value.is_some())
+                {
+                    queue.push_back(idx);
+                }
+            }
+        }
+
+        enqueue(&mut queue, connections, wires);
+
+        while let Some(idx) = queue.pop_front() {
+            let connection = &connections[idx];
+            let [lhs, rhs] = [
+                wires[&connection.input[0]].value.unwrap(),
+                wires[&connection.input[1]].value.unwrap(),
+            ];
+            let result = connection.gate.apply(lhs, rhs);
+
+            wires.get_mut(&connection.output).unwrap().value = Some(result);
+
+            if queue.is_empty() {
+                enqueue(&mut queue, connections, wires);
+            }
+        }
+    }
+
+    /// The next two gate outputs to swap to make the gates a ripple-carry
+    /// adder, or `None` if they already are one.
+    fn find_swap(connections: &[Connection]) -> Result<Option<(String, String)>, AocError> {
+        let mut carry = Self::expect_connection(connections, "x00", "y00", Gate::And)?;
+
+        for i in 1..45 {
+            let x = format!("x{:02}", i);
+            let y = format!("y{:02}", i);
+            let z = format!("z{:02}", i);
+
+            // z = x XOR y XOR carry
+            // c_n+1 = (x AND y) OR ((x XOR y) AND c_n)
+
+            let xor1 = Self::expect_connection(connections, &x, &y, Gate::Xor)?;
+            let and1 = Self::expect_connection(connections, &x, &y, Gate::And)?;
+
+            let Some(and2) =
+                Self::find_connection(connections, &xor1.output, &carry.output, Gate::And)
+            else {
+                return Ok(Some((xor1.output, and1.output)));
+            };
+            let xor2 =
+                Self::expect_connection(connections, &xor1.output, &carry.output, Gate::Xor)?;
+
+            if xor2.output != z {
+                if !connections.iter().any(|c| c.output == z) {
+                    return Err(AocError::new(format!(
+                        "expected a gate with output `{}`",
+                        z
+                    )));
+                }
+                return Ok(Some((xor2.output, z)));
+            }
+
+            if and1.output.starts_with("z") {
+                return Ok(Some((and1.output, xor2.output)));
+            }
+
+            carry = Self::expect_connection(connections, &and1.output, &and2.output, Gate::Or)?;
+        }
+
+        Ok(None)
+    }
+
+    fn swap_outputs(connections: &mut [Connection], a: &str, b: &str) {
+        for connection in connections {
+            if connection.output == a {
+                connection.output = b.to_string();
+            } else if connection.output == b {
+                connection.output = a.to_string();
+            }
+        }
+    }
+
+    fn find_connection(
+        connections: &[Connection],
+        left: &str,
+        right: &str,
+        gate: Gate,
+    ) -> Option<Connection> {
+        connections
+            .iter()
+            .find(|c| c.matches(left, right, &gate))
+            .cloned()
+    }
+
+    fn expect_connection(
+        connections: &[Connection],
+        left: &str,
+        right: &str,
+        gate: Gate,
+    ) -> Result<Connection, AocError> {
+        let name = gate.name();
+        Self::find_connection(connections, left, right, gate)
+            .ok_or_else(|| AocError::new(format!("expected a gate `{} {} {}`", left, name, right)))
+    }
+
+    fn parse_input(input: &str) -> Result<(HashMap<String, Wire>, Vec<Connection>), AocError> {
+        let lines = split_lines_whitespace(input)?;
+        let mut wires = HashMap::new();
+        let mut connections = vec![];
+
+        for (row, line) in lines.0.iter().enumerate() {
+            let [name, value] = utils::fields(row, line, line, line.split(':'))?;
+            let bit = utils::parse_field(row, line, value)?;
+            if bit > 1 {
+                return Err(AocError::at(
+                    row,
+                    utils::column(line, value.trim()),
+                    format!("expected 0 or 1, found `{}`", bit),
+                ));
+            }
+            wires.insert(name.trim().to_string(), Wire::new(Some(bit)));
+        }
+
+        for (i, line) in lines.1.iter().enumerate() {
+            let row = lines.0.len() + 1 + i;
+            let [left, gate, right, arrow, output] =
+                utils::fields(row, line, line, line.split_whitespace())?;
+            if arrow != "->" {
+                return Err(AocError::at(
+                    row,
+                    utils::column(line, arrow),
+                    format!("expected `->`, found `{}`", arrow),
+                ));
+            }
+            let gate = match gate {
+                "AND" => Gate::And,
+                "OR" => Gate::Or,
+                "XOR" => Gate::Xor,
+                _ => {
+                    return Err(AocError::at(
+                        row,
+                        utils::column(line, gate),
+                        format!("expected `AND`, `OR` or `XOR`, found `{}`", gate),
+                    ))
+                }
+            };
+            let (left, right, output) = (left.to_string(), right.to_string(), output.to_string());
+
+            for name in [&left, &right, &output] {
+                wires.entry(name.clone()).or_insert_with(|| Wire::new(None));
+            }
+
+            connections.push(Connection::new(left, right, gate, output));
+        }
+
+        Ok((wires, connections))
+    }
+}
+
+impl AocDay for AocDay24 {
+    type Input = AocDay24;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay24::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1()?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part2()?.into())
+    }
+
+    /// A 45-bit ripple-carry adder, the size part 2 expects, with the outputs
+    /// of four pairs of gates swapped within four of its full adders.
+    fn generate(rng: &mut Rng, _size: usize) -> Option<String> {
+        const BITS: usize = 45;
+
+        let mut names: Vec<String> = ('a'..='w')
+            .flat_map(|a| ('a'..='z').flat_map(move |b| ('a'..='z').map(move |c| [a, b, c])))
+            .map(|name| name.iter().collect())
+            .collect();
+        rng.shuffle(&mut names);
+        let mut names = names.into_iter();
+        let mut wire = || names.next().unwrap();
+
+        // Each bit's gates: x XOR y, x AND y, the sum, the carry AND and OR.
+        let mut gates = Vec::new();
+        let mut carry = wire();
+        let (x, y) = ("x00".to_string(), "y00".to_string());
+        gates.push((x.clone(), "XOR", y.clone(), "z00".to_string()));
+        gates.push((x, "AND", y, carry.clone()));
+        for bit in 1..BITS {
+            let (x, y, z) = (
+                format!("x{:02}", bit),
+                format!("y{:02}", bit),
+                format!("z{:02}", bit),
+            );
+            let (half, both, through) = (wire(), wire(), wire());
+            let next = if bit == BITS - 1 {
+                format!("z{:02}", BITS)
+            } else {
+                wire()
+            };
+            gates.push((x.clone(), "XOR", y.clone(), half.clone()));
+            gates.push((x, "AND", y, both.clone()));
+            gates.push((half.clone(), "XOR", carry.clone(), z));
+            gates.push((half, "AND", carry, through.clone()));
+            gates.push((both, "OR", through, next.clone()));
+            carry = next;
+        }
+
+        let mut bits: Vec<usize> = (1..BITS - 1).collect();
+        rng.shuffle(&mut bits);
+        for &bit in &bits[..4] {
+            // The bit's gates start at 2 + 5 * (bit - 1); swap the sum with
+            // another of its gates, or the two half adder outputs.
+            let first = 2 + 5 * (bit - 1);
+            let (a, b) = match rng.below(4) {
+                0 => (first, first + 1),
+                other => (first + 2, first + [1, 3, 4][other - 1]),
+            };
+            let output = gates[a].3.clone();
+            gates[a].3 = std::mem::replace(&mut gates[b].3, output);
+        }
+
+        let mut input = String::new();
+        for register in ['x', 'y'] {
+            for bit in 0..BITS {
+                input += &format!("{}{:02}: {}\n", register, bit, rng.below(2));
+            }
+        }
+        input.push('\n');
+        rng.shuffle(&mut gates);
+        for (mut left, gate, mut right, output) in gates {
+            if rng.chance(0.5) {
+                std::mem::swap(&mut left, &mut right);
+            }
+            input += &format!("{} {} {} -> {}\n", left, gate, right, output);
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_part1;
+
+    const EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay24>(EXAMPLE, 4);
+    }
+
+    #[test]
+    fn reports_inputs_that_are_not_an_adder() {
+        let input = AocDay24::new(EXAMPLE).unwrap();
+        assert_eq!(
+            input.part2().unwrap_err().to_string(),
+            "expected a gate `x01 AND y01`"
+        );
+
+        // `z01` depends on a wire that nothing sets.
+        let input = AocDay24::new("x00: 1\n\nx00 AND w00 -> z01\nx00 OR x00 -> z00\n").unwrap();
+        assert_eq!(
+            input.part1().unwrap_err().to_string(),
+            "wire `z01` never gets a value"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{random::Rng, utils::split_multi_lines_whitespace, Answer, AocDay, AocError};
+
+pub struct AocDay25 {
+    schemas: Vec<Vec<String>>,
+}
+
+impl AocDay25 {
+    pub fn new(input: &str) -> Result<AocDay25, AocError> {
+        let schemas = split_multi_lines_whitespace(input);
+
+        let mut row = 0;
+        for schema in &schemas {
+            if schema.is_empty() {
+                return Err(AocError::at_row(
+                    row,
+                    "expected a schematic, found a blank line",
+                ));
+            }
+            for line in schema {
+                if let Some((col, c)) = line
+                    .chars()
+                    .enumerate()
+                    .find(|&(_, c)| c != '#' && c != '.')
+                {
+                    return Err(AocError::at(
+                        row,
+                        col,
+                        format!("expected `#` or `.`, found `{}`", c),
+                    ));
+                }
+                row += 1;
+            }
+            row += 1;
+        }
+
+        Ok(AocDay25 { schemas })
+    }
+
+    pub fn part1(&self) -> usize {
+        let (locks, keys): (Vec<_>, Vec<_>) = self
+            .schemas
+            .iter()
+            .partition(|schema| Self::is_lock(schema));
+
+        let max_height = 5;
+        let mut combinations = 0;
+
+        for lock in &locks {
+            let lock_pins = Self::get_pin_heights(lock, true);
+
+            for key in &keys {
+                let key_pins = Self::get_pin_heights(key, false);
+
+                if lock_pins
+                    .iter()
+                    .zip(&key_pins)
+                    .all(|(&lock, &key)| key <= max_height - lock)
+                {
+                    combinations += 1;
+                }
+            }
+        }
+
+        combinations
+    }
+
+    fn is_lock(schema: &[String]) -> bool {
+        schema
+            .first()
+            .is_some_and(|row| row.chars().all(|c| c == '#'))
+    }
+
+    fn get_pin_heights(schema: &[String], is_lock: bool) -> Vec<usize> {
+        let width = schema[0].len();
+        let height = schema.len();
+        let mut heights = vec![0; width];
+
+        for col in 0..width {
+            heights[col] = if is_lock {
+                (1..height)
+                    .take_while(|&row| schema[row].chars().nth(col).unwrap_or('.') == '#')
+                    .count()
+            } else {
+                (0..height - 1)
+                    .rev()
+                    .take_while(|&row| schema[row].chars().nth(col).unwrap_or('.') == '#')
+                    .count()
+            };
+        }
+
+        heights
+    }
+}
+
+impl AocDay for AocDay25 {
+    type Input = AocDay25;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        AocDay25::new(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(input.part1().into())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(Answer::NotApplicable)
+    }
+
+    /// `size` locks and keys, with random pin heights.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut schematics = Vec::new();
+        for _ in 0..size.max(2) {
+            let heights = [(); 5].map(|_| rng.below(6));
+            let lock = rng.chance(0.5);
+            let rows: Vec<String> = (0..7)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|&height| {
+                            let filled = match lock {
+                                true => row <= height,
+                                false => row >= 6 - height,
+                            };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            schematics.push(rows.join("\n") + "\n");
+        }
+        Some(schematics.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{check_part1, check_part2};
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn part1_example() {
+        check_part1::<AocDay25>(EXAMPLE, 3);
+    }
+
+    #[test]
+    fn part2_is_not_applicable() {
+        check_part2::<AocDay25>(EXAMPLE, Answer::NotApplicable);
+    }
+}
@@ -0,0 +1,29 @@
+//! Advent of Code 2024.
+
+days! {
+    1 => day01::AocDay1,
+    2 => day02::AocDay2,
+    3 => day03::AocDay3,
+    4 => day04::AocDay4,
+    5 => day05::AocDay5,
+    6 => day06::AocDay6,
+    7 => day07::AocDay7,
+    8 => day08::AocDay8,
+    9 => day09::AocDay9,
+    10 => day10::AocDay10,
+    11 => day11::AocDay11,
+    12 => day12::AocDay12,
+    13 => day13::AocDay13,
+    14 => day14::AocDay14,
+    15 => day15::AocDay15,
+    16 => day16::AocDay16,
+    17 => day17::AocDay17,
+    18 => day18::AocDay18,
+    19 => day19::AocDay19,
+    20 => day20::AocDay20,
+    21 => day21::AocDay21,
+    22 => day22::AocDay22,
+    23 => day23::AocDay23,
+    24 => day24::AocDay24,
+    25 => day25::AocDay25,
+}